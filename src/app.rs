@@ -1,15 +1,33 @@
 use super::*;
 
 /// An id for a steam app/game
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AppId(pub u32);
+
+impl AppId {
+    /// An invalid app id, useful as a sentinel value
+    pub const INVALID: AppId = AppId(0);
+}
+
 impl From<u32> for AppId {
     fn from(id: u32) -> Self {
         AppId(id)
     }
 }
 
+impl From<AppId> for u32 {
+    fn from(id: AppId) -> u32 {
+        id.0
+    }
+}
+
+impl fmt::Display for AppId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 /// Access to the steam apps interface
 pub struct Apps {
     pub(crate) apps: *mut sys::ISteamApps,
@@ -31,6 +49,54 @@ impl Apps {
         unsafe { sys::SteamAPI_ISteamApps_BIsDlcInstalled(self.apps, app_id.0) }
     }
 
+    /// Returns the number of DLCs for the current app.
+    ///
+    /// This is used for iterating over the DLCs with
+    /// [`dlc_data_by_index()`](#method.dlc_data_by_index).
+    pub fn dlc_count(&self) -> i32 {
+        unsafe { sys::SteamAPI_ISteamApps_GetDLCCount(self.apps) }
+    }
+
+    /// Returns metadata for the DLC at `index`, between 0 and
+    /// [`dlc_count()`](#method.dlc_count).
+    ///
+    /// Returns `None` if the index is out of range.
+    pub fn dlc_data_by_index(&self, index: i32) -> Option<DlcData> {
+        unsafe {
+            let mut app_id = 0;
+            let mut available = false;
+            let mut name = [0 as c_char; 128];
+            if sys::SteamAPI_ISteamApps_BGetDLCDataByIndex(
+                self.apps,
+                index,
+                &mut app_id,
+                &mut available,
+                name.as_mut_ptr(),
+                name.len() as _,
+            ) {
+                Some(DlcData {
+                    app_id: AppId(app_id),
+                    available,
+                    name: CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned(),
+                })
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Requests the installation of an optional DLC.
+    ///
+    /// A [`DlcInstalled`] callback is posted once the DLC has been installed.
+    pub fn install_dlc(&self, app_id: AppId) {
+        unsafe { sys::SteamAPI_ISteamApps_InstallDLC(self.apps, app_id.0) }
+    }
+
+    /// Requests the uninstallation of an optional DLC.
+    pub fn uninstall_dlc(&self, app_id: AppId) {
+        unsafe { sys::SteamAPI_ISteamApps_UninstallDLC(self.apps, app_id.0) }
+    }
+
     /// Returns whether the user is subscribed to the app with the given
     /// ID.
     ///
@@ -45,6 +111,15 @@ impl Apps {
         unsafe { sys::SteamAPI_ISteamApps_BIsSubscribedFromFreeWeekend(self.apps) }
     }
 
+    /// Returns whether the user is playing the current app through a license
+    /// borrowed via Steam Family Sharing.
+    ///
+    /// In that case [`app_owner()`](#method.app_owner) returns the steam id of
+    /// the user that lent the app.
+    pub fn is_subscribed_from_family_sharing(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamApps_BIsSubscribedFromFamilySharing(self.apps) }
+    }
+
     /// Returns whether the user has a VAC ban on their account.
     pub fn is_vac_banned(&self) -> bool {
         unsafe { sys::SteamAPI_ISteamApps_BIsVACBanned(self.apps) }
@@ -98,12 +173,19 @@ impl Apps {
     }
 
     /// Returns a list of languages that the current app supports.
+    ///
+    /// The languages are the API language codes, e.g. `"english"`, matching
+    /// the value returned by [`current_game_language()`](#method.current_game_language).
     pub fn available_game_languages(&self) -> Vec<String> {
         unsafe {
             let langs = sys::SteamAPI_ISteamApps_GetAvailableGameLanguages(self.apps);
             let langs = CStr::from_ptr(langs);
             let langs = langs.to_string_lossy();
-            langs.split(',').map(|v| v.to_owned()).collect()
+            langs
+                .split(',')
+                .filter(|v| !v.is_empty())
+                .map(|v| v.to_owned())
+                .collect()
         }
     }
 
@@ -143,10 +225,14 @@ impl Apps {
     ///
     /// If the game was not launched through Steam URL, this returns an empty string.
     ///
+    /// When the game is already running and a new Steam URL is executed, a
+    /// [`NewUrlLaunchParameters`] callback is posted and this returns the new
+    /// command line.
+    ///
     /// See [Steam API](https://partner.steamgames.com/doc/api/ISteamApps#GetLaunchCommandLine)
     pub fn launch_command_line(&self) -> String {
         unsafe {
-            let mut buffer = vec![0; 256];
+            let mut buffer = vec![0; 1024];
             let _bytes = sys::SteamAPI_ISteamApps_GetLaunchCommandLine(
                 self.apps,
                 buffer.as_mut_ptr(),
@@ -173,6 +259,29 @@ impl Apps {
     }
 }
 
+/// Metadata about a DLC of the current app
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DlcData {
+    pub app_id: AppId,
+    /// Whether the DLC is currently available on the store
+    pub available: bool,
+    pub name: String,
+}
+
+/// Called after the user gains ownership of a DLC and it has been installed.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DlcInstalled {
+    pub app_id: AppId,
+}
+
+impl_callback!(cb: DlcInstalled_t => DlcInstalled {
+    Self {
+        app_id: AppId(cb.m_nAppID),
+    }
+});
+
 /// Called after the user executes a steam url with command line or query parameters such as steam://run/<appid>//?param1=value1;param2=value2;param3=value3; while the game is already running.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -0,0 +1,113 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+use super::*;
+
+/// The pending result of an asynchronous steam api call
+///
+/// Wraps any of the callback based methods, e.g. [`UGC::create_item`], so the
+/// result can either be polled once per frame with [`try_take`](Self::try_take)
+/// or `.await`ed.
+///
+/// The result only arrives while callbacks are being run, so
+/// [`Client::run_callbacks`] still has to be called regularly, e.g. from the
+/// game loop or a separate thread.
+///
+/// ```no_run
+/// # use steamworks::*;
+/// # let client = steamworks::Client::init().unwrap();
+/// let ugc = client.ugc();
+/// let created = CallResult::new(|done| ugc.create_item(AppId(480), FileType::Community, done));
+/// loop {
+///     client.run_callbacks();
+///     if let Some(result) = created.try_take() {
+///         println!("Created item: {:?}", result);
+///         break;
+///     }
+///     std::thread::sleep(std::time::Duration::from_millis(100));
+/// }
+/// ```
+pub struct CallResult<T> {
+    state: Arc<Mutex<CallResultState<T>>>,
+}
+
+struct CallResultState<T> {
+    result: Option<T>,
+    waker: Option<Waker>,
+}
+
+impl<T: Send + 'static> CallResult<T> {
+    /// Starts an asynchronous call.
+    ///
+    /// `start` is called immediately with the function that completes the
+    /// call, which should be passed on as the callback of the wrapped method.
+    pub fn new<F>(start: F) -> CallResult<T>
+    where
+        F: FnOnce(Box<dyn FnOnce(T) + Send>),
+    {
+        let state = Arc::new(Mutex::new(CallResultState {
+            result: None,
+            waker: None,
+        }));
+        let complete_state = state.clone();
+        start(Box::new(move |result| {
+            let mut state = complete_state.lock().unwrap();
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }));
+        CallResult { state }
+    }
+}
+
+impl<T> CallResult<T> {
+    /// Returns whether the result has arrived and not been taken yet
+    pub fn is_ready(&self) -> bool {
+        self.state.lock().unwrap().result.is_some()
+    }
+
+    /// Takes the result if it has arrived.
+    ///
+    /// Returns `None` if the call is still pending or the result has
+    /// already been taken.
+    pub fn try_take(&self) -> Option<T> {
+        self.state.lock().unwrap().result.take()
+    }
+}
+
+impl<T> Future for CallResult<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.state.lock().unwrap();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn call_result_completes() {
+        let mut complete = None;
+        let result = CallResult::new(|done| complete = Some(done));
+        assert!(!result.is_ready());
+        assert_eq!(None, result.try_take());
+
+        std::thread::spawn(move || complete.unwrap()(5u32))
+            .join()
+            .unwrap();
+        assert!(result.is_ready());
+        assert_eq!(Some(5), result.try_take());
+        assert_eq!(None, result.try_take());
+    }
+}
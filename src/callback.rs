@@ -49,6 +49,12 @@ pub enum CallbackResult {
     GSClientKick(GSClientKick),
     GSClientGroupStatus(GSClientGroupStatus),
     NewUrlLaunchParameters(NewUrlLaunchParameters),
+    InventoryResultReady(InventoryResultReady),
+    PlaybackStatusHasChanged(PlaybackStatusHasChanged),
+    VolumeHasChanged(VolumeHasChanged),
+    DlcInstalled(DlcInstalled),
+    ActiveBeaconsUpdated(ActiveBeaconsUpdated),
+    ReservationNotificationCallback(ReservationNotificationCallback),
 }
 
 impl CallbackResult {
@@ -121,6 +127,20 @@ impl CallbackResult {
             NewUrlLaunchParameters::ID => {
                 Self::NewUrlLaunchParameters(NewUrlLaunchParameters::from_raw(data))
             }
+            InventoryResultReady::ID => {
+                Self::InventoryResultReady(InventoryResultReady::from_raw(data))
+            }
+            PlaybackStatusHasChanged::ID => {
+                Self::PlaybackStatusHasChanged(PlaybackStatusHasChanged::from_raw(data))
+            }
+            VolumeHasChanged::ID => Self::VolumeHasChanged(VolumeHasChanged::from_raw(data)),
+            DlcInstalled::ID => Self::DlcInstalled(DlcInstalled::from_raw(data)),
+            ActiveBeaconsUpdated::ID => {
+                Self::ActiveBeaconsUpdated(ActiveBeaconsUpdated::from_raw(data))
+            }
+            ReservationNotificationCallback::ID => Self::ReservationNotificationCallback(
+                ReservationNotificationCallback::from_raw(data),
+            ),
             _ => return None,
         })
     }
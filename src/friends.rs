@@ -106,6 +106,27 @@ impl Friends {
         }
     }
 
+    /// Returns the users in a source (lobby, game server or clan)
+    /// that the current user can see.
+    ///
+    /// Users in a lobby are only visible once the lobby has been joined.
+    pub fn get_friends_from_source(&self, source: SteamId) -> Vec<Friend> {
+        unsafe {
+            let count =
+                sys::SteamAPI_ISteamFriends_GetFriendCountFromSource(self.friends, source.0);
+            let mut friends = Vec::with_capacity(count.max(0) as usize);
+            for idx in 0..count {
+                let friend = SteamId(sys::SteamAPI_ISteamFriends_GetFriendFromSourceByIndex(
+                    self.friends,
+                    source.0,
+                    idx,
+                ));
+                friends.push(self.get_friend(friend));
+            }
+            friends
+        }
+    }
+
     pub fn get_friend(&self, friend: SteamId) -> Friend {
         Friend {
             id: friend,
@@ -213,6 +234,30 @@ impl Friends {
         }
     }
 
+    /// Gets the number of users following the specified user.
+    ///
+    /// This is an asynchronous request, the count is passed to the
+    /// callback once it has been retrieved from the steam servers.
+    pub fn get_follower_count<F>(&self, user: SteamId, cb: F)
+    where
+        F: FnOnce(SResult<u32>) + 'static + Send,
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamFriends_GetFollowerCount(self.friends, user.0);
+            register_call_result::<sys::FriendsGetFollowerCount_t, _>(
+                &self.inner,
+                api_call,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else {
+                        crate::to_steam_result(v.m_eResult).map(|_| v.m_nCount as u32)
+                    })
+                },
+            );
+        }
+    }
+
     /// Clears all of the current user's Rich Presence key/values.
     pub fn clear_rich_presence(&self) {
         unsafe {
@@ -0,0 +1,188 @@
+use super::*;
+
+/// Access to the steam HTTP interface
+///
+/// Makes HTTP requests through the Steam client, e.g. to fetch patch notes
+/// or content manifests.
+pub struct Http {
+    pub(crate) http: *mut sys::ISteamHTTP,
+    pub(crate) inner: Arc<Inner>,
+}
+
+/// The method of an HTTP request
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HttpRequestMethod {
+    Get,
+    Head,
+    Post,
+    Put,
+    Delete,
+    Options,
+    Patch,
+}
+
+impl From<HttpRequestMethod> for sys::EHTTPMethod {
+    fn from(method: HttpRequestMethod) -> sys::EHTTPMethod {
+        match method {
+            HttpRequestMethod::Get => sys::EHTTPMethod::k_EHTTPMethodGET,
+            HttpRequestMethod::Head => sys::EHTTPMethod::k_EHTTPMethodHEAD,
+            HttpRequestMethod::Post => sys::EHTTPMethod::k_EHTTPMethodPOST,
+            HttpRequestMethod::Put => sys::EHTTPMethod::k_EHTTPMethodPUT,
+            HttpRequestMethod::Delete => sys::EHTTPMethod::k_EHTTPMethodDELETE,
+            HttpRequestMethod::Options => sys::EHTTPMethod::k_EHTTPMethodOPTIONS,
+            HttpRequestMethod::Patch => sys::EHTTPMethod::k_EHTTPMethodPATCH,
+        }
+    }
+}
+
+/// A handle to an HTTP request created with [`Http::create_http_request`]
+///
+/// The request has to be released with [`Http::release_http_request`] once
+/// it is no longer needed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HttpRequestHandle(pub(crate) sys::HTTPRequestHandle);
+
+/// The result of a completed HTTP request
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HttpRequestCompleted {
+    /// The request that completed
+    pub request: HttpRequestHandle,
+    /// The HTTP status code of the response
+    pub status_code: u32,
+    /// The size of the response body in bytes
+    pub body_size: u32,
+}
+
+impl Http {
+    /// Creates a new HTTP request for the given absolute url.
+    ///
+    /// Returns `None` if the url is invalid.
+    pub fn create_http_request(
+        &self,
+        method: HttpRequestMethod,
+        url: &str,
+    ) -> Option<HttpRequestHandle> {
+        let url = CString::new(url).ok()?;
+        unsafe {
+            let handle =
+                sys::SteamAPI_ISteamHTTP_CreateHTTPRequest(self.http, method.into(), url.as_ptr());
+            if handle == sys::INVALID_HTTPREQUEST_HANDLE {
+                None
+            } else {
+                Some(HttpRequestHandle(handle))
+            }
+        }
+    }
+
+    /// Sets a header on the request.
+    ///
+    /// Returns `false` if the request is invalid or already sent, or if the
+    /// header is one Steam manages itself.
+    pub fn set_http_request_header_value(
+        &self,
+        request: HttpRequestHandle,
+        header: &str,
+        value: &str,
+    ) -> bool {
+        let header = CString::new(header).unwrap();
+        let value = CString::new(value).unwrap();
+        unsafe {
+            sys::SteamAPI_ISteamHTTP_SetHTTPRequestHeaderValue(
+                self.http,
+                request.0,
+                header.as_ptr(),
+                value.as_ptr(),
+            )
+        }
+    }
+
+    /// Sets a parameter on the request.
+    ///
+    /// For `GET` requests the parameter is added to the query string, for
+    /// `POST` requests it is sent in the body.
+    pub fn set_http_request_get_or_post_parameter(
+        &self,
+        request: HttpRequestHandle,
+        name: &str,
+        value: &str,
+    ) -> bool {
+        let name = CString::new(name).unwrap();
+        let value = CString::new(value).unwrap();
+        unsafe {
+            sys::SteamAPI_ISteamHTTP_SetHTTPRequestGetOrPostParameter(
+                self.http,
+                request.0,
+                name.as_ptr(),
+                value.as_ptr(),
+            )
+        }
+    }
+
+    /// Sends the request.
+    ///
+    /// The callback is called once the request completes. On success the
+    /// response body can be read with
+    /// [`get_http_response_body_data()`](#method.get_http_response_body_data).
+    /// The request still has to be released afterwards.
+    pub fn send_http_request<F>(&self, request: HttpRequestHandle, cb: F)
+    where
+        F: FnOnce(SResult<HttpRequestCompleted>) + 'static + Send,
+    {
+        unsafe {
+            let mut api_call = 0;
+            if !sys::SteamAPI_ISteamHTTP_SendHTTPRequest(self.http, request.0, &mut api_call) {
+                cb(Err(SteamError::InvalidParameter));
+                return;
+            }
+            register_call_result::<sys::HTTPRequestCompleted_t, _>(
+                &self.inner,
+                api_call,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else if !v.m_bRequestSuccessful {
+                        Err(SteamError::NoConnection)
+                    } else {
+                        Ok(HttpRequestCompleted {
+                            request: HttpRequestHandle(v.m_hRequest),
+                            status_code: v.m_eStatusCode as u32,
+                            body_size: v.m_unBodySize,
+                        })
+                    })
+                },
+            );
+        }
+    }
+
+    /// Returns the body of a completed request.
+    ///
+    /// Returns `None` if the request is invalid or hasn't completed yet.
+    pub fn get_http_response_body_data(&self, request: HttpRequestHandle) -> Option<Vec<u8>> {
+        unsafe {
+            let mut size = 0;
+            if !sys::SteamAPI_ISteamHTTP_GetHTTPResponseBodySize(self.http, request.0, &mut size) {
+                return None;
+            }
+            let mut body = vec![0; size as usize];
+            if !sys::SteamAPI_ISteamHTTP_GetHTTPResponseBodyData(
+                self.http,
+                request.0,
+                body.as_mut_ptr(),
+                size,
+            ) {
+                return None;
+            }
+            Some(body)
+        }
+    }
+
+    /// Releases the request and frees its associated memory.
+    ///
+    /// If the request is still in progress it is cancelled.
+    pub fn release_http_request(&self, request: HttpRequestHandle) -> bool {
+        unsafe { sys::SteamAPI_ISteamHTTP_ReleaseHTTPRequest(self.http, request.0) }
+    }
+}
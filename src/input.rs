@@ -2,6 +2,11 @@ use sys::InputHandle_t;
 
 use super::*;
 
+/// Special input handle that addresses all connected controllers at once.
+///
+/// Defined as a macro in the SDK headers, so it isn't part of the bindings.
+const STEAM_INPUT_HANDLE_ALL_CONTROLLERS: InputHandle_t = u64::MAX;
+
 /// Access to the steam input interface
 pub struct Input {
     pub(crate) input: *mut sys::ISteamInput,
@@ -26,12 +31,77 @@ pub enum InputType {
     SteamDeckController,
 }
 
+/// The mode of the physical input an action is bound to, e.g. the mode of
+/// `InputAnalogActionData_t::eMode`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InputSourceMode {
+    None,
+    Dpad,
+    Buttons,
+    FourButtons,
+    AbsoluteMouse,
+    RelativeMouse,
+    JoystickMove,
+    JoystickMouse,
+    JoystickCamera,
+    ScrollWheel,
+    Trigger,
+    TouchMenu,
+    MouseJoystick,
+    MouseRegion,
+    RadialMenu,
+    SingleButton,
+    Switches,
+}
+
+impl From<sys::EInputSourceMode> for InputSourceMode {
+    fn from(mode: sys::EInputSourceMode) -> InputSourceMode {
+        match mode {
+            sys::EInputSourceMode::k_EInputSourceMode_Dpad => InputSourceMode::Dpad,
+            sys::EInputSourceMode::k_EInputSourceMode_Buttons => InputSourceMode::Buttons,
+            sys::EInputSourceMode::k_EInputSourceMode_FourButtons => InputSourceMode::FourButtons,
+            sys::EInputSourceMode::k_EInputSourceMode_AbsoluteMouse => {
+                InputSourceMode::AbsoluteMouse
+            }
+            sys::EInputSourceMode::k_EInputSourceMode_RelativeMouse => {
+                InputSourceMode::RelativeMouse
+            }
+            sys::EInputSourceMode::k_EInputSourceMode_JoystickMove => InputSourceMode::JoystickMove,
+            sys::EInputSourceMode::k_EInputSourceMode_JoystickMouse => {
+                InputSourceMode::JoystickMouse
+            }
+            sys::EInputSourceMode::k_EInputSourceMode_JoystickCamera => {
+                InputSourceMode::JoystickCamera
+            }
+            sys::EInputSourceMode::k_EInputSourceMode_ScrollWheel => InputSourceMode::ScrollWheel,
+            sys::EInputSourceMode::k_EInputSourceMode_Trigger => InputSourceMode::Trigger,
+            sys::EInputSourceMode::k_EInputSourceMode_TouchMenu => InputSourceMode::TouchMenu,
+            sys::EInputSourceMode::k_EInputSourceMode_MouseJoystick => {
+                InputSourceMode::MouseJoystick
+            }
+            sys::EInputSourceMode::k_EInputSourceMode_MouseRegion => InputSourceMode::MouseRegion,
+            sys::EInputSourceMode::k_EInputSourceMode_RadialMenu => InputSourceMode::RadialMenu,
+            sys::EInputSourceMode::k_EInputSourceMode_SingleButton => InputSourceMode::SingleButton,
+            sys::EInputSourceMode::k_EInputSourceMode_Switches => InputSourceMode::Switches,
+            _ => InputSourceMode::None,
+        }
+    }
+}
+
 impl Input {
     /// Init must be called when starting use of this interface.
     /// if explicitly_call_run_frame is called then you will need to manually call RunFrame
     /// each frame, otherwise Steam Input will updated when SteamAPI_RunCallbacks() is called
-    pub fn init(&self, explicitly_call_run_frame: bool) -> bool {
-        unsafe { sys::SteamAPI_ISteamInput_Init(self.input, explicitly_call_run_frame) }
+    ///
+    /// Returns `Err(SteamError::InitFailed)` if Steam Input couldn't be initialized.
+    pub fn init(&self, explicitly_call_run_frame: bool) -> SResult<()> {
+        let ok = unsafe { sys::SteamAPI_ISteamInput_Init(self.input, explicitly_call_run_frame) };
+        if ok {
+            Ok(())
+        } else {
+            Err(SteamError::InitFailed)
+        }
     }
 
     /// Synchronize API state with the latest Steam Input action data available. This
@@ -43,21 +113,50 @@ impl Input {
         unsafe { sys::SteamAPI_ISteamInput_RunFrame(self.input, false) }
     }
 
+    /// Waits on an IPC event from Steam sent when there is new data to be fetched from
+    /// the data drop.
+    ///
+    /// Blocks for at most `timeout_ms` milliseconds. Returns `true` if new data was received
+    /// and `false` on timeout. If `wait_forever` is set the timeout is ignored and this blocks
+    /// until data arrives.
+    pub fn wait_for_data(&self, wait_forever: bool, timeout_ms: u32) -> bool {
+        unsafe { sys::SteamAPI_ISteamInput_BWaitForData(self.input, wait_forever, timeout_ms) }
+    }
+
+    /// Returns `true` if new data has been received since the last time action data was
+    /// accessed via `get_digital_action_data` or `get_analog_action_data`.
+    ///
+    /// Unlike [`wait_for_data`](Self::wait_for_data) this doesn't block, which suits
+    /// polling from a game loop.
+    pub fn new_data_available(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamInput_BNewDataAvailable(self.input) }
+    }
+
     /// Returns a list of the currently connected controllers
     pub fn get_connected_controllers(&self) -> Vec<sys::InputHandle_t> {
-        let mut handles = vec![0_u64; sys::STEAM_INPUT_MAX_COUNT as usize];
+        let mut handles = [0_u64; sys::STEAM_INPUT_MAX_COUNT as usize];
         let quantity = self.get_connected_controllers_slice(&mut handles);
-        handles.shrink_to(quantity);
-        handles
+        handles[..quantity].to_vec()
+    }
+
+    /// Returns a list of the currently connected controllers along with their input type
+    ///
+    /// Useful for showing the correct glyphs or UI for each controller.
+    pub fn get_connected_controllers_with_types(&self) -> Vec<(sys::InputHandle_t, InputType)> {
+        self.get_connected_controllers()
+            .into_iter()
+            .map(|handle| (handle, self.get_input_type_for_handle(handle)))
+            .collect()
     }
 
     /// Returns a list of the currently connected controllers without allocating, and the count
+    ///
+    /// The buffer always has room for `STEAM_INPUT_MAX_COUNT` handles, so its size is
+    /// checked at compile time.
     pub fn get_connected_controllers_slice(
         &self,
-        mut controllers: impl AsMut<[InputHandle_t]>,
+        handles: &mut [InputHandle_t; sys::STEAM_INPUT_MAX_COUNT as usize],
     ) -> usize {
-        let handles = controllers.as_mut();
-        assert!(handles.len() >= sys::STEAM_INPUT_MAX_COUNT as usize);
         unsafe {
             return sys::SteamAPI_ISteamInput_GetConnectedControllers(
                 self.input,
@@ -67,10 +166,17 @@ impl Input {
     }
 
     /// Allows to load a specific Action Manifest File localy
-    pub fn set_input_action_manifest_file_path(&self, path: &str) -> bool {
+    ///
+    /// Returns `Err(SteamError::FileNotFound)` if the manifest couldn't be loaded.
+    pub fn set_input_action_manifest_file_path(&self, path: &str) -> SResult<()> {
         let path = CString::new(path).unwrap();
-        unsafe {
+        let ok = unsafe {
             sys::SteamAPI_ISteamInput_SetInputActionManifestFilePath(self.input, path.as_ptr())
+        };
+        if ok {
+            Ok(())
+        } else {
+            Err(SteamError::FileNotFound)
         }
     }
 
@@ -150,6 +256,71 @@ impl Input {
         }
     }
 
+    /// Reconfigure all connected controllers to use the specified action set.
+    ///
+    /// Useful when the whole game switches context, e.g. from a menu to gameplay.
+    pub fn activate_action_set_for_all_controllers(
+        &self,
+        action_set_handle: sys::InputActionSetHandle_t,
+    ) {
+        self.activate_action_set_handle(STEAM_INPUT_HANDLE_ALL_CONTROLLERS, action_set_handle)
+    }
+
+    /// Reconfigure the controller to use the specified action set layer on top of its
+    /// current action set.
+    ///
+    /// Layer handles are looked up by name with [`get_action_set_handle`](Self::get_action_set_handle),
+    /// the same way as action sets.
+    pub fn activate_action_set_layer(
+        &self,
+        input_handle: sys::InputHandle_t,
+        action_set_layer_handle: sys::InputActionSetHandle_t,
+    ) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_ActivateActionSetLayer(
+                self.input,
+                input_handle,
+                action_set_layer_handle,
+            )
+        }
+    }
+
+    /// Removes the specified action set layer from the controller.
+    pub fn deactivate_action_set_layer(
+        &self,
+        input_handle: sys::InputHandle_t,
+        action_set_layer_handle: sys::InputActionSetHandle_t,
+    ) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_DeactivateActionSetLayer(
+                self.input,
+                input_handle,
+                action_set_layer_handle,
+            )
+        }
+    }
+
+    /// Returns the action set layers currently active on the controller.
+    ///
+    /// Steam doesn't expose the layers defined in the action manifest, so this together
+    /// with [`get_action_set_handle`](Self::get_action_set_handle) is the way to inspect
+    /// layers at runtime.
+    pub fn get_active_action_set_layers(
+        &self,
+        input_handle: sys::InputHandle_t,
+    ) -> Vec<sys::InputActionSetHandle_t> {
+        unsafe {
+            let mut layers = Vec::with_capacity(sys::STEAM_INPUT_MAX_ACTIVE_LAYERS as usize);
+            let len = sys::SteamAPI_ISteamInput_GetActiveActionSetLayers(
+                self.input,
+                input_handle,
+                layers.as_mut_ptr(),
+            );
+            layers.set_len(len as usize);
+            layers
+        }
+    }
+
     /// Get the handle of the specified Digital action.
     pub fn get_digital_action_handle(&self, action_name: &str) -> sys::InputDigitalActionHandle_t {
         let name = CString::new(action_name).unwrap();
@@ -162,6 +333,76 @@ impl Input {
         unsafe { sys::SteamAPI_ISteamInput_GetAnalogActionHandle(self.input, name.as_ptr()) }
     }
 
+    /// Returns the localized display name of the Digital action with the given handle.
+    ///
+    /// Takes the handle of the action, see
+    /// [`get_string_for_digital_action`](Self::get_string_for_digital_action) to look
+    /// the display name up by the action's name instead.
+    ///
+    /// Returns `None` if the handle is unknown.
+    pub fn get_string_for_digital_action_name(
+        &self,
+        action_handle: sys::InputDigitalActionHandle_t,
+    ) -> Option<String> {
+        unsafe {
+            let name =
+                sys::SteamAPI_ISteamInput_GetStringForDigitalActionName(self.input, action_handle);
+            if name.is_null() {
+                return None;
+            }
+            Some(CStr::from_ptr(name).to_string_lossy().into_owned())
+        }
+    }
+
+    /// Returns the localized display name of the Analog action with the given handle.
+    ///
+    /// Takes the handle of the action, see
+    /// [`get_string_for_analog_action`](Self::get_string_for_analog_action) to look
+    /// the display name up by the action's name instead.
+    ///
+    /// Returns `None` if the handle is unknown.
+    pub fn get_string_for_analog_action_name(
+        &self,
+        action_handle: sys::InputAnalogActionHandle_t,
+    ) -> Option<String> {
+        unsafe {
+            let name =
+                sys::SteamAPI_ISteamInput_GetStringForAnalogActionName(self.input, action_handle);
+            if name.is_null() {
+                return None;
+            }
+            Some(CStr::from_ptr(name).to_string_lossy().into_owned())
+        }
+    }
+
+    /// Returns the localized display name of the Digital action with the given name.
+    ///
+    /// Takes the name of the action as defined in the action manifest, unlike
+    /// [`get_string_for_digital_action_name`](Self::get_string_for_digital_action_name)
+    /// which takes its handle.
+    ///
+    /// Returns `None` if there is no Digital action with that name.
+    pub fn get_string_for_digital_action(&self, action_name: &str) -> Option<String> {
+        match self.get_digital_action_handle(action_name) {
+            0 => None,
+            handle => self.get_string_for_digital_action_name(handle),
+        }
+    }
+
+    /// Returns the localized display name of the Analog action with the given name.
+    ///
+    /// Takes the name of the action as defined in the action manifest, unlike
+    /// [`get_string_for_analog_action_name`](Self::get_string_for_analog_action_name)
+    /// which takes its handle.
+    ///
+    /// Returns `None` if there is no Analog action with that name.
+    pub fn get_string_for_analog_action(&self, action_name: &str) -> Option<String> {
+        match self.get_analog_action_handle(action_name) {
+            0 => None,
+            handle => self.get_string_for_analog_action_name(handle),
+        }
+    }
+
     /// Returns the current state of the supplied digital game action.
     pub fn get_digital_action_data(
         &self,
@@ -184,13 +425,29 @@ impl Input {
         }
     }
 
+    /// Returns the current state of the supplied analog game action along with the
+    /// origin(s) it is bound to within the action set.
+    ///
+    /// Useful for drawing on-screen hints, which need both the value and the glyph.
+    pub fn get_analog_action_data_and_origins(
+        &self,
+        input_handle: sys::InputHandle_t,
+        action_set_handle: sys::InputActionSetHandle_t,
+        analog_action_handle: sys::InputAnalogActionHandle_t,
+    ) -> (sys::InputAnalogActionData_t, Vec<ActionOrigin>) {
+        let data = self.get_analog_action_data(input_handle, analog_action_handle);
+        let origins =
+            self.get_analog_action_origins(input_handle, action_set_handle, analog_action_handle);
+        (data, origins)
+    }
+
     /// Get the origin(s) for a digital action within an action set.
     pub fn get_digital_action_origins(
         &self,
         input_handle: sys::InputHandle_t,
         action_set_handle: sys::InputActionSetHandle_t,
         digital_action_handle: sys::InputDigitalActionHandle_t,
-    ) -> Vec<sys::EInputActionOrigin> {
+    ) -> Vec<ActionOrigin> {
         unsafe {
             let mut origins = Vec::with_capacity(sys::STEAM_INPUT_MAX_ORIGINS as usize);
             let len = sys::SteamAPI_ISteamInput_GetDigitalActionOrigins(
@@ -201,7 +458,7 @@ impl Input {
                 origins.as_mut_ptr(),
             );
             origins.set_len(len as usize);
-            origins
+            origins.into_iter().map(ActionOrigin::from).collect()
         }
     }
 
@@ -211,7 +468,7 @@ impl Input {
         input_handle: sys::InputHandle_t,
         action_set_handle: sys::InputActionSetHandle_t,
         analog_action_handle: sys::InputAnalogActionHandle_t,
-    ) -> Vec<sys::EInputActionOrigin> {
+    ) -> Vec<ActionOrigin> {
         unsafe {
             let mut origins = Vec::with_capacity(sys::STEAM_INPUT_MAX_ORIGINS as usize);
             let len = sys::SteamAPI_ISteamInput_GetAnalogActionOrigins(
@@ -222,21 +479,112 @@ impl Input {
                 origins.as_mut_ptr(),
             );
             origins.set_len(len as usize);
-            origins
+            origins.into_iter().map(ActionOrigin::from).collect()
         }
     }
 
-    pub fn get_motion_data(&self, input_handle: sys::InputHandle_t) -> sys::InputMotionData_t {
-        unsafe { sys::SteamAPI_ISteamInput_GetMotionData(self.input, input_handle) }
+    /// Get the origin(s) for a digital action within an action set without allocating,
+    /// and the count
+    pub fn get_digital_action_origins_slice(
+        &self,
+        input_handle: sys::InputHandle_t,
+        action_set_handle: sys::InputActionSetHandle_t,
+        digital_action_handle: sys::InputDigitalActionHandle_t,
+        origins: &mut [ActionOrigin; sys::STEAM_INPUT_MAX_ORIGINS as usize],
+    ) -> usize {
+        let mut raw = [sys::EInputActionOrigin::k_EInputActionOrigin_None;
+            sys::STEAM_INPUT_MAX_ORIGINS as usize];
+        let len = unsafe {
+            sys::SteamAPI_ISteamInput_GetDigitalActionOrigins(
+                self.input,
+                input_handle,
+                action_set_handle,
+                digital_action_handle,
+                raw.as_mut_ptr(),
+            ) as usize
+        };
+        for (origin, raw) in origins.iter_mut().zip(&raw[..len]) {
+            *origin = (*raw).into();
+        }
+        len
+    }
+
+    /// Get the origin(s) for an analog action within an action set without allocating,
+    /// and the count
+    pub fn get_analog_action_origins_slice(
+        &self,
+        input_handle: sys::InputHandle_t,
+        action_set_handle: sys::InputActionSetHandle_t,
+        analog_action_handle: sys::InputAnalogActionHandle_t,
+        origins: &mut [ActionOrigin; sys::STEAM_INPUT_MAX_ORIGINS as usize],
+    ) -> usize {
+        let mut raw = [sys::EInputActionOrigin::k_EInputActionOrigin_None;
+            sys::STEAM_INPUT_MAX_ORIGINS as usize];
+        let len = unsafe {
+            sys::SteamAPI_ISteamInput_GetAnalogActionOrigins(
+                self.input,
+                input_handle,
+                action_set_handle,
+                analog_action_handle,
+                raw.as_mut_ptr(),
+            ) as usize
+        };
+        for (origin, raw) in origins.iter_mut().zip(&raw[..len]) {
+            *origin = (*raw).into();
+        }
+        len
+    }
+
+    /// Returns the raw motion data from the controller's gyroscope and accelerometer.
+    pub fn get_motion_data(&self, input_handle: sys::InputHandle_t) -> MotionData {
+        unsafe { sys::SteamAPI_ISteamInput_GetMotionData(self.input, input_handle).into() }
+    }
+
+    /// Applies all effects set in `feedback` to the controller.
+    ///
+    /// Effects that aren't set are left untouched. Controllers without the
+    /// corresponding hardware silently ignore the effect.
+    pub fn set_controller_feedback(
+        &self,
+        input_handle: sys::InputHandle_t,
+        feedback: ControllerFeedback,
+    ) {
+        unsafe {
+            if let Some((r, g, b)) = feedback.led_color {
+                sys::SteamAPI_ISteamInput_SetLEDColor(
+                    self.input,
+                    input_handle,
+                    r,
+                    g,
+                    b,
+                    sys::ESteamInputLEDFlag::k_ESteamInputLEDFlag_SetColor as _,
+                );
+            }
+            if let Some(haptic) = feedback.haptic {
+                sys::SteamAPI_ISteamInput_TriggerVibrationExtended(
+                    self.input,
+                    input_handle,
+                    haptic.left_speed,
+                    haptic.right_speed,
+                    haptic.left_trigger_speed,
+                    haptic.right_trigger_speed,
+                );
+            }
+        }
     }
 
     /// Invokes the Steam overlay and brings up the binding screen.
-    /// Returns true for success, false if overlay is disabled/unavailable.
+    /// Returns `Err(SteamError::Disabled)` if overlay is disabled/unavailable.
     /// If the player is using Big Picture Mode the configuration will open in
     /// the overlay. In desktop mode a popup window version of Big Picture will
     /// be created and open the configuration.
-    pub fn show_binding_panel(&self, input_handle: sys::InputHandle_t) -> bool {
-        unsafe { sys::SteamAPI_ISteamInput_ShowBindingPanel(self.input, input_handle) }
+    pub fn show_binding_panel(&self, input_handle: sys::InputHandle_t) -> SResult<()> {
+        let ok = unsafe { sys::SteamAPI_ISteamInput_ShowBindingPanel(self.input, input_handle) };
+        if ok {
+            Ok(())
+        } else {
+            Err(SteamError::Disabled)
+        }
     }
 
     /// Shutdown must be called when ending use of this interface.
@@ -246,3 +594,99 @@ impl Input {
         }
     }
 }
+
+/// Motion data of a controller, as returned by [`Input::get_motion_data`]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MotionData {
+    rotation: [f32; 4],
+    positional_acceleration: [f32; 3],
+    rotational_velocity: [f32; 3],
+}
+
+impl MotionData {
+    /// The absolute rotation of the controller as a quaternion in `[x, y, z, w]` order
+    ///
+    /// The rotation is sensor-fused from the gyroscope and drifts over time.
+    pub fn rotation(&self) -> [f32; 4] {
+        self.rotation
+    }
+
+    /// The positional acceleration of the controller in `[x, y, z]` order
+    pub fn positional_acceleration(&self) -> [f32; 3] {
+        self.positional_acceleration
+    }
+
+    /// The angular velocity of the controller in `[x, y, z]` order
+    pub fn rotational_velocity(&self) -> [f32; 3] {
+        self.rotational_velocity
+    }
+
+    /// The normalized direction of gravity relative to the controller
+    ///
+    /// Derived from the positional acceleration, so it is only accurate while
+    /// the controller isn't moving much. Returns `None` if there is no
+    /// acceleration at all.
+    pub fn gravity_direction(&self) -> Option<[f32; 3]> {
+        let [x, y, z] = self.positional_acceleration;
+        let len = (x * x + y * y + z * z).sqrt();
+        if len == 0.0 {
+            return None;
+        }
+        // The accelerometer measures the force holding the controller up,
+        // which points away from gravity
+        Some([-x / len, -y / len, -z / len])
+    }
+}
+
+impl From<sys::InputMotionData_t> for MotionData {
+    fn from(data: sys::InputMotionData_t) -> MotionData {
+        MotionData {
+            rotation: [data.rotQuatX, data.rotQuatY, data.rotQuatZ, data.rotQuatW],
+            positional_acceleration: [data.posAccelX, data.posAccelY, data.posAccelZ],
+            rotational_velocity: [data.rotVelX, data.rotVelY, data.rotVelZ],
+        }
+    }
+}
+
+/// A set of feedback effects to apply to a controller with
+/// [`Input::set_controller_feedback`]
+///
+/// DualSense adaptive trigger effects aren't supported, as the SDK only
+/// exposes `ScePadTriggerEffectParam` as an opaque type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ControllerFeedback {
+    led_color: Option<(u8, u8, u8)>,
+    haptic: Option<HapticFeedback>,
+}
+
+impl ControllerFeedback {
+    /// Creates an empty set of effects
+    pub fn new() -> ControllerFeedback {
+        ControllerFeedback::default()
+    }
+
+    /// Sets the color of the controller's LED.
+    pub fn led_color(mut self, r: u8, g: u8, b: u8) -> ControllerFeedback {
+        self.led_color = Some((r, g, b));
+        self
+    }
+
+    /// Triggers a vibration of the controller's motors.
+    pub fn haptic(mut self, haptic: HapticFeedback) -> ControllerFeedback {
+        self.haptic = Some(haptic);
+        self
+    }
+}
+
+/// The speeds of a controller's vibration motors
+///
+/// The trigger speeds are only used by controllers with trigger motors,
+/// e.g. the Xbox One controller.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HapticFeedback {
+    pub left_speed: u16,
+    pub right_speed: u16,
+    pub left_trigger_speed: u16,
+    pub right_trigger_speed: u16,
+}
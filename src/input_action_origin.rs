@@ -0,0 +1,2006 @@
+use super::*;
+
+/// A physical button, trigger, pad or stick an action is bound to
+///
+/// Mirrors `EInputActionOrigin`, e.g. as returned by
+/// [`Input::get_digital_action_origins`], without the `k_EInputActionOrigin_`
+/// prefix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(u32)]
+pub enum ActionOrigin {
+    None = 0,
+    SteamControllerA = 1,
+    SteamControllerB = 2,
+    SteamControllerX = 3,
+    SteamControllerY = 4,
+    SteamControllerLeftBumper = 5,
+    SteamControllerRightBumper = 6,
+    SteamControllerLeftGrip = 7,
+    SteamControllerRightGrip = 8,
+    SteamControllerStart = 9,
+    SteamControllerBack = 10,
+    SteamControllerLeftPadTouch = 11,
+    SteamControllerLeftPadSwipe = 12,
+    SteamControllerLeftPadClick = 13,
+    SteamControllerLeftPadDPadNorth = 14,
+    SteamControllerLeftPadDPadSouth = 15,
+    SteamControllerLeftPadDPadWest = 16,
+    SteamControllerLeftPadDPadEast = 17,
+    SteamControllerRightPadTouch = 18,
+    SteamControllerRightPadSwipe = 19,
+    SteamControllerRightPadClick = 20,
+    SteamControllerRightPadDPadNorth = 21,
+    SteamControllerRightPadDPadSouth = 22,
+    SteamControllerRightPadDPadWest = 23,
+    SteamControllerRightPadDPadEast = 24,
+    SteamControllerLeftTriggerPull = 25,
+    SteamControllerLeftTriggerClick = 26,
+    SteamControllerRightTriggerPull = 27,
+    SteamControllerRightTriggerClick = 28,
+    SteamControllerLeftStickMove = 29,
+    SteamControllerLeftStickClick = 30,
+    SteamControllerLeftStickDPadNorth = 31,
+    SteamControllerLeftStickDPadSouth = 32,
+    SteamControllerLeftStickDPadWest = 33,
+    SteamControllerLeftStickDPadEast = 34,
+    SteamControllerGyroMove = 35,
+    SteamControllerGyroPitch = 36,
+    SteamControllerGyroYaw = 37,
+    SteamControllerGyroRoll = 38,
+    SteamControllerReserved0 = 39,
+    SteamControllerReserved1 = 40,
+    SteamControllerReserved2 = 41,
+    SteamControllerReserved3 = 42,
+    SteamControllerReserved4 = 43,
+    SteamControllerReserved5 = 44,
+    SteamControllerReserved6 = 45,
+    SteamControllerReserved7 = 46,
+    SteamControllerReserved8 = 47,
+    SteamControllerReserved9 = 48,
+    SteamControllerReserved10 = 49,
+    PS4X = 50,
+    PS4Circle = 51,
+    PS4Triangle = 52,
+    PS4Square = 53,
+    PS4LeftBumper = 54,
+    PS4RightBumper = 55,
+    PS4Options = 56,
+    PS4Share = 57,
+    PS4LeftPadTouch = 58,
+    PS4LeftPadSwipe = 59,
+    PS4LeftPadClick = 60,
+    PS4LeftPadDPadNorth = 61,
+    PS4LeftPadDPadSouth = 62,
+    PS4LeftPadDPadWest = 63,
+    PS4LeftPadDPadEast = 64,
+    PS4RightPadTouch = 65,
+    PS4RightPadSwipe = 66,
+    PS4RightPadClick = 67,
+    PS4RightPadDPadNorth = 68,
+    PS4RightPadDPadSouth = 69,
+    PS4RightPadDPadWest = 70,
+    PS4RightPadDPadEast = 71,
+    PS4CenterPadTouch = 72,
+    PS4CenterPadSwipe = 73,
+    PS4CenterPadClick = 74,
+    PS4CenterPadDPadNorth = 75,
+    PS4CenterPadDPadSouth = 76,
+    PS4CenterPadDPadWest = 77,
+    PS4CenterPadDPadEast = 78,
+    PS4LeftTriggerPull = 79,
+    PS4LeftTriggerClick = 80,
+    PS4RightTriggerPull = 81,
+    PS4RightTriggerClick = 82,
+    PS4LeftStickMove = 83,
+    PS4LeftStickClick = 84,
+    PS4LeftStickDPadNorth = 85,
+    PS4LeftStickDPadSouth = 86,
+    PS4LeftStickDPadWest = 87,
+    PS4LeftStickDPadEast = 88,
+    PS4RightStickMove = 89,
+    PS4RightStickClick = 90,
+    PS4RightStickDPadNorth = 91,
+    PS4RightStickDPadSouth = 92,
+    PS4RightStickDPadWest = 93,
+    PS4RightStickDPadEast = 94,
+    PS4DPadNorth = 95,
+    PS4DPadSouth = 96,
+    PS4DPadWest = 97,
+    PS4DPadEast = 98,
+    PS4GyroMove = 99,
+    PS4GyroPitch = 100,
+    PS4GyroYaw = 101,
+    PS4GyroRoll = 102,
+    PS4DPadMove = 103,
+    PS4Reserved1 = 104,
+    PS4Reserved2 = 105,
+    PS4Reserved3 = 106,
+    PS4Reserved4 = 107,
+    PS4Reserved5 = 108,
+    PS4Reserved6 = 109,
+    PS4Reserved7 = 110,
+    PS4Reserved8 = 111,
+    PS4Reserved9 = 112,
+    PS4Reserved10 = 113,
+    XBoxOneA = 114,
+    XBoxOneB = 115,
+    XBoxOneX = 116,
+    XBoxOneY = 117,
+    XBoxOneLeftBumper = 118,
+    XBoxOneRightBumper = 119,
+    XBoxOneMenu = 120,
+    XBoxOneView = 121,
+    XBoxOneLeftTriggerPull = 122,
+    XBoxOneLeftTriggerClick = 123,
+    XBoxOneRightTriggerPull = 124,
+    XBoxOneRightTriggerClick = 125,
+    XBoxOneLeftStickMove = 126,
+    XBoxOneLeftStickClick = 127,
+    XBoxOneLeftStickDPadNorth = 128,
+    XBoxOneLeftStickDPadSouth = 129,
+    XBoxOneLeftStickDPadWest = 130,
+    XBoxOneLeftStickDPadEast = 131,
+    XBoxOneRightStickMove = 132,
+    XBoxOneRightStickClick = 133,
+    XBoxOneRightStickDPadNorth = 134,
+    XBoxOneRightStickDPadSouth = 135,
+    XBoxOneRightStickDPadWest = 136,
+    XBoxOneRightStickDPadEast = 137,
+    XBoxOneDPadNorth = 138,
+    XBoxOneDPadSouth = 139,
+    XBoxOneDPadWest = 140,
+    XBoxOneDPadEast = 141,
+    XBoxOneDPadMove = 142,
+    XBoxOneLeftGripLower = 143,
+    XBoxOneLeftGripUpper = 144,
+    XBoxOneRightGripLower = 145,
+    XBoxOneRightGripUpper = 146,
+    XBoxOneShare = 147,
+    XBoxOneReserved6 = 148,
+    XBoxOneReserved7 = 149,
+    XBoxOneReserved8 = 150,
+    XBoxOneReserved9 = 151,
+    XBoxOneReserved10 = 152,
+    XBox360A = 153,
+    XBox360B = 154,
+    XBox360X = 155,
+    XBox360Y = 156,
+    XBox360LeftBumper = 157,
+    XBox360RightBumper = 158,
+    XBox360Start = 159,
+    XBox360Back = 160,
+    XBox360LeftTriggerPull = 161,
+    XBox360LeftTriggerClick = 162,
+    XBox360RightTriggerPull = 163,
+    XBox360RightTriggerClick = 164,
+    XBox360LeftStickMove = 165,
+    XBox360LeftStickClick = 166,
+    XBox360LeftStickDPadNorth = 167,
+    XBox360LeftStickDPadSouth = 168,
+    XBox360LeftStickDPadWest = 169,
+    XBox360LeftStickDPadEast = 170,
+    XBox360RightStickMove = 171,
+    XBox360RightStickClick = 172,
+    XBox360RightStickDPadNorth = 173,
+    XBox360RightStickDPadSouth = 174,
+    XBox360RightStickDPadWest = 175,
+    XBox360RightStickDPadEast = 176,
+    XBox360DPadNorth = 177,
+    XBox360DPadSouth = 178,
+    XBox360DPadWest = 179,
+    XBox360DPadEast = 180,
+    XBox360DPadMove = 181,
+    XBox360Reserved1 = 182,
+    XBox360Reserved2 = 183,
+    XBox360Reserved3 = 184,
+    XBox360Reserved4 = 185,
+    XBox360Reserved5 = 186,
+    XBox360Reserved6 = 187,
+    XBox360Reserved7 = 188,
+    XBox360Reserved8 = 189,
+    XBox360Reserved9 = 190,
+    XBox360Reserved10 = 191,
+    SwitchA = 192,
+    SwitchB = 193,
+    SwitchX = 194,
+    SwitchY = 195,
+    SwitchLeftBumper = 196,
+    SwitchRightBumper = 197,
+    SwitchPlus = 198,
+    SwitchMinus = 199,
+    SwitchCapture = 200,
+    SwitchLeftTriggerPull = 201,
+    SwitchLeftTriggerClick = 202,
+    SwitchRightTriggerPull = 203,
+    SwitchRightTriggerClick = 204,
+    SwitchLeftStickMove = 205,
+    SwitchLeftStickClick = 206,
+    SwitchLeftStickDPadNorth = 207,
+    SwitchLeftStickDPadSouth = 208,
+    SwitchLeftStickDPadWest = 209,
+    SwitchLeftStickDPadEast = 210,
+    SwitchRightStickMove = 211,
+    SwitchRightStickClick = 212,
+    SwitchRightStickDPadNorth = 213,
+    SwitchRightStickDPadSouth = 214,
+    SwitchRightStickDPadWest = 215,
+    SwitchRightStickDPadEast = 216,
+    SwitchDPadNorth = 217,
+    SwitchDPadSouth = 218,
+    SwitchDPadWest = 219,
+    SwitchDPadEast = 220,
+    SwitchProGyroMove = 221,
+    SwitchProGyroPitch = 222,
+    SwitchProGyroYaw = 223,
+    SwitchProGyroRoll = 224,
+    SwitchDPadMove = 225,
+    SwitchReserved1 = 226,
+    SwitchReserved2 = 227,
+    SwitchReserved3 = 228,
+    SwitchReserved4 = 229,
+    SwitchReserved5 = 230,
+    SwitchReserved6 = 231,
+    SwitchReserved7 = 232,
+    SwitchReserved8 = 233,
+    SwitchReserved9 = 234,
+    SwitchReserved10 = 235,
+    SwitchRightGyroMove = 236,
+    SwitchRightGyroPitch = 237,
+    SwitchRightGyroYaw = 238,
+    SwitchRightGyroRoll = 239,
+    SwitchLeftGyroMove = 240,
+    SwitchLeftGyroPitch = 241,
+    SwitchLeftGyroYaw = 242,
+    SwitchLeftGyroRoll = 243,
+    SwitchLeftGripLower = 244,
+    SwitchLeftGripUpper = 245,
+    SwitchRightGripLower = 246,
+    SwitchRightGripUpper = 247,
+    SwitchJoyConButtonN = 248,
+    SwitchJoyConButtonE = 249,
+    SwitchJoyConButtonS = 250,
+    SwitchJoyConButtonW = 251,
+    SwitchReserved15 = 252,
+    SwitchReserved16 = 253,
+    SwitchReserved17 = 254,
+    SwitchReserved18 = 255,
+    SwitchReserved19 = 256,
+    SwitchReserved20 = 257,
+    PS5X = 258,
+    PS5Circle = 259,
+    PS5Triangle = 260,
+    PS5Square = 261,
+    PS5LeftBumper = 262,
+    PS5RightBumper = 263,
+    PS5Option = 264,
+    PS5Create = 265,
+    PS5Mute = 266,
+    PS5LeftPadTouch = 267,
+    PS5LeftPadSwipe = 268,
+    PS5LeftPadClick = 269,
+    PS5LeftPadDPadNorth = 270,
+    PS5LeftPadDPadSouth = 271,
+    PS5LeftPadDPadWest = 272,
+    PS5LeftPadDPadEast = 273,
+    PS5RightPadTouch = 274,
+    PS5RightPadSwipe = 275,
+    PS5RightPadClick = 276,
+    PS5RightPadDPadNorth = 277,
+    PS5RightPadDPadSouth = 278,
+    PS5RightPadDPadWest = 279,
+    PS5RightPadDPadEast = 280,
+    PS5CenterPadTouch = 281,
+    PS5CenterPadSwipe = 282,
+    PS5CenterPadClick = 283,
+    PS5CenterPadDPadNorth = 284,
+    PS5CenterPadDPadSouth = 285,
+    PS5CenterPadDPadWest = 286,
+    PS5CenterPadDPadEast = 287,
+    PS5LeftTriggerPull = 288,
+    PS5LeftTriggerClick = 289,
+    PS5RightTriggerPull = 290,
+    PS5RightTriggerClick = 291,
+    PS5LeftStickMove = 292,
+    PS5LeftStickClick = 293,
+    PS5LeftStickDPadNorth = 294,
+    PS5LeftStickDPadSouth = 295,
+    PS5LeftStickDPadWest = 296,
+    PS5LeftStickDPadEast = 297,
+    PS5RightStickMove = 298,
+    PS5RightStickClick = 299,
+    PS5RightStickDPadNorth = 300,
+    PS5RightStickDPadSouth = 301,
+    PS5RightStickDPadWest = 302,
+    PS5RightStickDPadEast = 303,
+    PS5DPadNorth = 304,
+    PS5DPadSouth = 305,
+    PS5DPadWest = 306,
+    PS5DPadEast = 307,
+    PS5GyroMove = 308,
+    PS5GyroPitch = 309,
+    PS5GyroYaw = 310,
+    PS5GyroRoll = 311,
+    PS5DPadMove = 312,
+    PS5LeftGrip = 313,
+    PS5RightGrip = 314,
+    PS5LeftFn = 315,
+    PS5RightFn = 316,
+    PS5Reserved5 = 317,
+    PS5Reserved6 = 318,
+    PS5Reserved7 = 319,
+    PS5Reserved8 = 320,
+    PS5Reserved9 = 321,
+    PS5Reserved10 = 322,
+    PS5Reserved11 = 323,
+    PS5Reserved12 = 324,
+    PS5Reserved13 = 325,
+    PS5Reserved14 = 326,
+    PS5Reserved15 = 327,
+    PS5Reserved16 = 328,
+    PS5Reserved17 = 329,
+    PS5Reserved18 = 330,
+    PS5Reserved19 = 331,
+    PS5Reserved20 = 332,
+    SteamDeckA = 333,
+    SteamDeckB = 334,
+    SteamDeckX = 335,
+    SteamDeckY = 336,
+    SteamDeckL1 = 337,
+    SteamDeckR1 = 338,
+    SteamDeckMenu = 339,
+    SteamDeckView = 340,
+    SteamDeckLeftPadTouch = 341,
+    SteamDeckLeftPadSwipe = 342,
+    SteamDeckLeftPadClick = 343,
+    SteamDeckLeftPadDPadNorth = 344,
+    SteamDeckLeftPadDPadSouth = 345,
+    SteamDeckLeftPadDPadWest = 346,
+    SteamDeckLeftPadDPadEast = 347,
+    SteamDeckRightPadTouch = 348,
+    SteamDeckRightPadSwipe = 349,
+    SteamDeckRightPadClick = 350,
+    SteamDeckRightPadDPadNorth = 351,
+    SteamDeckRightPadDPadSouth = 352,
+    SteamDeckRightPadDPadWest = 353,
+    SteamDeckRightPadDPadEast = 354,
+    SteamDeckL2SoftPull = 355,
+    SteamDeckL2 = 356,
+    SteamDeckR2SoftPull = 357,
+    SteamDeckR2 = 358,
+    SteamDeckLeftStickMove = 359,
+    SteamDeckL3 = 360,
+    SteamDeckLeftStickDPadNorth = 361,
+    SteamDeckLeftStickDPadSouth = 362,
+    SteamDeckLeftStickDPadWest = 363,
+    SteamDeckLeftStickDPadEast = 364,
+    SteamDeckLeftStickTouch = 365,
+    SteamDeckRightStickMove = 366,
+    SteamDeckR3 = 367,
+    SteamDeckRightStickDPadNorth = 368,
+    SteamDeckRightStickDPadSouth = 369,
+    SteamDeckRightStickDPadWest = 370,
+    SteamDeckRightStickDPadEast = 371,
+    SteamDeckRightStickTouch = 372,
+    SteamDeckL4 = 373,
+    SteamDeckR4 = 374,
+    SteamDeckL5 = 375,
+    SteamDeckR5 = 376,
+    SteamDeckDPadMove = 377,
+    SteamDeckDPadNorth = 378,
+    SteamDeckDPadSouth = 379,
+    SteamDeckDPadWest = 380,
+    SteamDeckDPadEast = 381,
+    SteamDeckGyroMove = 382,
+    SteamDeckGyroPitch = 383,
+    SteamDeckGyroYaw = 384,
+    SteamDeckGyroRoll = 385,
+    SteamDeckReserved1 = 386,
+    SteamDeckReserved2 = 387,
+    SteamDeckReserved3 = 388,
+    SteamDeckReserved4 = 389,
+    SteamDeckReserved5 = 390,
+    SteamDeckReserved6 = 391,
+    SteamDeckReserved7 = 392,
+    SteamDeckReserved8 = 393,
+    SteamDeckReserved9 = 394,
+    SteamDeckReserved10 = 395,
+    SteamDeckReserved11 = 396,
+    SteamDeckReserved12 = 397,
+    SteamDeckReserved13 = 398,
+    SteamDeckReserved14 = 399,
+    SteamDeckReserved15 = 400,
+    SteamDeckReserved16 = 401,
+    SteamDeckReserved17 = 402,
+    SteamDeckReserved18 = 403,
+    SteamDeckReserved19 = 404,
+    SteamDeckReserved20 = 405,
+    HoripadM1 = 406,
+    HoripadM2 = 407,
+    HoripadL4 = 408,
+    HoripadR4 = 409,
+}
+
+/// Returned when a value doesn't correspond to any [`ActionOrigin`]
+#[derive(Clone, Copy, Debug, Error, PartialEq, Eq)]
+#[error("{0} is not a valid action origin")]
+pub struct InvalidActionOrigin(pub u32);
+
+impl TryFrom<u32> for ActionOrigin {
+    type Error = InvalidActionOrigin;
+
+    fn try_from(value: u32) -> Result<ActionOrigin, InvalidActionOrigin> {
+        Ok(match value {
+            0 => ActionOrigin::None,
+            1 => ActionOrigin::SteamControllerA,
+            2 => ActionOrigin::SteamControllerB,
+            3 => ActionOrigin::SteamControllerX,
+            4 => ActionOrigin::SteamControllerY,
+            5 => ActionOrigin::SteamControllerLeftBumper,
+            6 => ActionOrigin::SteamControllerRightBumper,
+            7 => ActionOrigin::SteamControllerLeftGrip,
+            8 => ActionOrigin::SteamControllerRightGrip,
+            9 => ActionOrigin::SteamControllerStart,
+            10 => ActionOrigin::SteamControllerBack,
+            11 => ActionOrigin::SteamControllerLeftPadTouch,
+            12 => ActionOrigin::SteamControllerLeftPadSwipe,
+            13 => ActionOrigin::SteamControllerLeftPadClick,
+            14 => ActionOrigin::SteamControllerLeftPadDPadNorth,
+            15 => ActionOrigin::SteamControllerLeftPadDPadSouth,
+            16 => ActionOrigin::SteamControllerLeftPadDPadWest,
+            17 => ActionOrigin::SteamControllerLeftPadDPadEast,
+            18 => ActionOrigin::SteamControllerRightPadTouch,
+            19 => ActionOrigin::SteamControllerRightPadSwipe,
+            20 => ActionOrigin::SteamControllerRightPadClick,
+            21 => ActionOrigin::SteamControllerRightPadDPadNorth,
+            22 => ActionOrigin::SteamControllerRightPadDPadSouth,
+            23 => ActionOrigin::SteamControllerRightPadDPadWest,
+            24 => ActionOrigin::SteamControllerRightPadDPadEast,
+            25 => ActionOrigin::SteamControllerLeftTriggerPull,
+            26 => ActionOrigin::SteamControllerLeftTriggerClick,
+            27 => ActionOrigin::SteamControllerRightTriggerPull,
+            28 => ActionOrigin::SteamControllerRightTriggerClick,
+            29 => ActionOrigin::SteamControllerLeftStickMove,
+            30 => ActionOrigin::SteamControllerLeftStickClick,
+            31 => ActionOrigin::SteamControllerLeftStickDPadNorth,
+            32 => ActionOrigin::SteamControllerLeftStickDPadSouth,
+            33 => ActionOrigin::SteamControllerLeftStickDPadWest,
+            34 => ActionOrigin::SteamControllerLeftStickDPadEast,
+            35 => ActionOrigin::SteamControllerGyroMove,
+            36 => ActionOrigin::SteamControllerGyroPitch,
+            37 => ActionOrigin::SteamControllerGyroYaw,
+            38 => ActionOrigin::SteamControllerGyroRoll,
+            39 => ActionOrigin::SteamControllerReserved0,
+            40 => ActionOrigin::SteamControllerReserved1,
+            41 => ActionOrigin::SteamControllerReserved2,
+            42 => ActionOrigin::SteamControllerReserved3,
+            43 => ActionOrigin::SteamControllerReserved4,
+            44 => ActionOrigin::SteamControllerReserved5,
+            45 => ActionOrigin::SteamControllerReserved6,
+            46 => ActionOrigin::SteamControllerReserved7,
+            47 => ActionOrigin::SteamControllerReserved8,
+            48 => ActionOrigin::SteamControllerReserved9,
+            49 => ActionOrigin::SteamControllerReserved10,
+            50 => ActionOrigin::PS4X,
+            51 => ActionOrigin::PS4Circle,
+            52 => ActionOrigin::PS4Triangle,
+            53 => ActionOrigin::PS4Square,
+            54 => ActionOrigin::PS4LeftBumper,
+            55 => ActionOrigin::PS4RightBumper,
+            56 => ActionOrigin::PS4Options,
+            57 => ActionOrigin::PS4Share,
+            58 => ActionOrigin::PS4LeftPadTouch,
+            59 => ActionOrigin::PS4LeftPadSwipe,
+            60 => ActionOrigin::PS4LeftPadClick,
+            61 => ActionOrigin::PS4LeftPadDPadNorth,
+            62 => ActionOrigin::PS4LeftPadDPadSouth,
+            63 => ActionOrigin::PS4LeftPadDPadWest,
+            64 => ActionOrigin::PS4LeftPadDPadEast,
+            65 => ActionOrigin::PS4RightPadTouch,
+            66 => ActionOrigin::PS4RightPadSwipe,
+            67 => ActionOrigin::PS4RightPadClick,
+            68 => ActionOrigin::PS4RightPadDPadNorth,
+            69 => ActionOrigin::PS4RightPadDPadSouth,
+            70 => ActionOrigin::PS4RightPadDPadWest,
+            71 => ActionOrigin::PS4RightPadDPadEast,
+            72 => ActionOrigin::PS4CenterPadTouch,
+            73 => ActionOrigin::PS4CenterPadSwipe,
+            74 => ActionOrigin::PS4CenterPadClick,
+            75 => ActionOrigin::PS4CenterPadDPadNorth,
+            76 => ActionOrigin::PS4CenterPadDPadSouth,
+            77 => ActionOrigin::PS4CenterPadDPadWest,
+            78 => ActionOrigin::PS4CenterPadDPadEast,
+            79 => ActionOrigin::PS4LeftTriggerPull,
+            80 => ActionOrigin::PS4LeftTriggerClick,
+            81 => ActionOrigin::PS4RightTriggerPull,
+            82 => ActionOrigin::PS4RightTriggerClick,
+            83 => ActionOrigin::PS4LeftStickMove,
+            84 => ActionOrigin::PS4LeftStickClick,
+            85 => ActionOrigin::PS4LeftStickDPadNorth,
+            86 => ActionOrigin::PS4LeftStickDPadSouth,
+            87 => ActionOrigin::PS4LeftStickDPadWest,
+            88 => ActionOrigin::PS4LeftStickDPadEast,
+            89 => ActionOrigin::PS4RightStickMove,
+            90 => ActionOrigin::PS4RightStickClick,
+            91 => ActionOrigin::PS4RightStickDPadNorth,
+            92 => ActionOrigin::PS4RightStickDPadSouth,
+            93 => ActionOrigin::PS4RightStickDPadWest,
+            94 => ActionOrigin::PS4RightStickDPadEast,
+            95 => ActionOrigin::PS4DPadNorth,
+            96 => ActionOrigin::PS4DPadSouth,
+            97 => ActionOrigin::PS4DPadWest,
+            98 => ActionOrigin::PS4DPadEast,
+            99 => ActionOrigin::PS4GyroMove,
+            100 => ActionOrigin::PS4GyroPitch,
+            101 => ActionOrigin::PS4GyroYaw,
+            102 => ActionOrigin::PS4GyroRoll,
+            103 => ActionOrigin::PS4DPadMove,
+            104 => ActionOrigin::PS4Reserved1,
+            105 => ActionOrigin::PS4Reserved2,
+            106 => ActionOrigin::PS4Reserved3,
+            107 => ActionOrigin::PS4Reserved4,
+            108 => ActionOrigin::PS4Reserved5,
+            109 => ActionOrigin::PS4Reserved6,
+            110 => ActionOrigin::PS4Reserved7,
+            111 => ActionOrigin::PS4Reserved8,
+            112 => ActionOrigin::PS4Reserved9,
+            113 => ActionOrigin::PS4Reserved10,
+            114 => ActionOrigin::XBoxOneA,
+            115 => ActionOrigin::XBoxOneB,
+            116 => ActionOrigin::XBoxOneX,
+            117 => ActionOrigin::XBoxOneY,
+            118 => ActionOrigin::XBoxOneLeftBumper,
+            119 => ActionOrigin::XBoxOneRightBumper,
+            120 => ActionOrigin::XBoxOneMenu,
+            121 => ActionOrigin::XBoxOneView,
+            122 => ActionOrigin::XBoxOneLeftTriggerPull,
+            123 => ActionOrigin::XBoxOneLeftTriggerClick,
+            124 => ActionOrigin::XBoxOneRightTriggerPull,
+            125 => ActionOrigin::XBoxOneRightTriggerClick,
+            126 => ActionOrigin::XBoxOneLeftStickMove,
+            127 => ActionOrigin::XBoxOneLeftStickClick,
+            128 => ActionOrigin::XBoxOneLeftStickDPadNorth,
+            129 => ActionOrigin::XBoxOneLeftStickDPadSouth,
+            130 => ActionOrigin::XBoxOneLeftStickDPadWest,
+            131 => ActionOrigin::XBoxOneLeftStickDPadEast,
+            132 => ActionOrigin::XBoxOneRightStickMove,
+            133 => ActionOrigin::XBoxOneRightStickClick,
+            134 => ActionOrigin::XBoxOneRightStickDPadNorth,
+            135 => ActionOrigin::XBoxOneRightStickDPadSouth,
+            136 => ActionOrigin::XBoxOneRightStickDPadWest,
+            137 => ActionOrigin::XBoxOneRightStickDPadEast,
+            138 => ActionOrigin::XBoxOneDPadNorth,
+            139 => ActionOrigin::XBoxOneDPadSouth,
+            140 => ActionOrigin::XBoxOneDPadWest,
+            141 => ActionOrigin::XBoxOneDPadEast,
+            142 => ActionOrigin::XBoxOneDPadMove,
+            143 => ActionOrigin::XBoxOneLeftGripLower,
+            144 => ActionOrigin::XBoxOneLeftGripUpper,
+            145 => ActionOrigin::XBoxOneRightGripLower,
+            146 => ActionOrigin::XBoxOneRightGripUpper,
+            147 => ActionOrigin::XBoxOneShare,
+            148 => ActionOrigin::XBoxOneReserved6,
+            149 => ActionOrigin::XBoxOneReserved7,
+            150 => ActionOrigin::XBoxOneReserved8,
+            151 => ActionOrigin::XBoxOneReserved9,
+            152 => ActionOrigin::XBoxOneReserved10,
+            153 => ActionOrigin::XBox360A,
+            154 => ActionOrigin::XBox360B,
+            155 => ActionOrigin::XBox360X,
+            156 => ActionOrigin::XBox360Y,
+            157 => ActionOrigin::XBox360LeftBumper,
+            158 => ActionOrigin::XBox360RightBumper,
+            159 => ActionOrigin::XBox360Start,
+            160 => ActionOrigin::XBox360Back,
+            161 => ActionOrigin::XBox360LeftTriggerPull,
+            162 => ActionOrigin::XBox360LeftTriggerClick,
+            163 => ActionOrigin::XBox360RightTriggerPull,
+            164 => ActionOrigin::XBox360RightTriggerClick,
+            165 => ActionOrigin::XBox360LeftStickMove,
+            166 => ActionOrigin::XBox360LeftStickClick,
+            167 => ActionOrigin::XBox360LeftStickDPadNorth,
+            168 => ActionOrigin::XBox360LeftStickDPadSouth,
+            169 => ActionOrigin::XBox360LeftStickDPadWest,
+            170 => ActionOrigin::XBox360LeftStickDPadEast,
+            171 => ActionOrigin::XBox360RightStickMove,
+            172 => ActionOrigin::XBox360RightStickClick,
+            173 => ActionOrigin::XBox360RightStickDPadNorth,
+            174 => ActionOrigin::XBox360RightStickDPadSouth,
+            175 => ActionOrigin::XBox360RightStickDPadWest,
+            176 => ActionOrigin::XBox360RightStickDPadEast,
+            177 => ActionOrigin::XBox360DPadNorth,
+            178 => ActionOrigin::XBox360DPadSouth,
+            179 => ActionOrigin::XBox360DPadWest,
+            180 => ActionOrigin::XBox360DPadEast,
+            181 => ActionOrigin::XBox360DPadMove,
+            182 => ActionOrigin::XBox360Reserved1,
+            183 => ActionOrigin::XBox360Reserved2,
+            184 => ActionOrigin::XBox360Reserved3,
+            185 => ActionOrigin::XBox360Reserved4,
+            186 => ActionOrigin::XBox360Reserved5,
+            187 => ActionOrigin::XBox360Reserved6,
+            188 => ActionOrigin::XBox360Reserved7,
+            189 => ActionOrigin::XBox360Reserved8,
+            190 => ActionOrigin::XBox360Reserved9,
+            191 => ActionOrigin::XBox360Reserved10,
+            192 => ActionOrigin::SwitchA,
+            193 => ActionOrigin::SwitchB,
+            194 => ActionOrigin::SwitchX,
+            195 => ActionOrigin::SwitchY,
+            196 => ActionOrigin::SwitchLeftBumper,
+            197 => ActionOrigin::SwitchRightBumper,
+            198 => ActionOrigin::SwitchPlus,
+            199 => ActionOrigin::SwitchMinus,
+            200 => ActionOrigin::SwitchCapture,
+            201 => ActionOrigin::SwitchLeftTriggerPull,
+            202 => ActionOrigin::SwitchLeftTriggerClick,
+            203 => ActionOrigin::SwitchRightTriggerPull,
+            204 => ActionOrigin::SwitchRightTriggerClick,
+            205 => ActionOrigin::SwitchLeftStickMove,
+            206 => ActionOrigin::SwitchLeftStickClick,
+            207 => ActionOrigin::SwitchLeftStickDPadNorth,
+            208 => ActionOrigin::SwitchLeftStickDPadSouth,
+            209 => ActionOrigin::SwitchLeftStickDPadWest,
+            210 => ActionOrigin::SwitchLeftStickDPadEast,
+            211 => ActionOrigin::SwitchRightStickMove,
+            212 => ActionOrigin::SwitchRightStickClick,
+            213 => ActionOrigin::SwitchRightStickDPadNorth,
+            214 => ActionOrigin::SwitchRightStickDPadSouth,
+            215 => ActionOrigin::SwitchRightStickDPadWest,
+            216 => ActionOrigin::SwitchRightStickDPadEast,
+            217 => ActionOrigin::SwitchDPadNorth,
+            218 => ActionOrigin::SwitchDPadSouth,
+            219 => ActionOrigin::SwitchDPadWest,
+            220 => ActionOrigin::SwitchDPadEast,
+            221 => ActionOrigin::SwitchProGyroMove,
+            222 => ActionOrigin::SwitchProGyroPitch,
+            223 => ActionOrigin::SwitchProGyroYaw,
+            224 => ActionOrigin::SwitchProGyroRoll,
+            225 => ActionOrigin::SwitchDPadMove,
+            226 => ActionOrigin::SwitchReserved1,
+            227 => ActionOrigin::SwitchReserved2,
+            228 => ActionOrigin::SwitchReserved3,
+            229 => ActionOrigin::SwitchReserved4,
+            230 => ActionOrigin::SwitchReserved5,
+            231 => ActionOrigin::SwitchReserved6,
+            232 => ActionOrigin::SwitchReserved7,
+            233 => ActionOrigin::SwitchReserved8,
+            234 => ActionOrigin::SwitchReserved9,
+            235 => ActionOrigin::SwitchReserved10,
+            236 => ActionOrigin::SwitchRightGyroMove,
+            237 => ActionOrigin::SwitchRightGyroPitch,
+            238 => ActionOrigin::SwitchRightGyroYaw,
+            239 => ActionOrigin::SwitchRightGyroRoll,
+            240 => ActionOrigin::SwitchLeftGyroMove,
+            241 => ActionOrigin::SwitchLeftGyroPitch,
+            242 => ActionOrigin::SwitchLeftGyroYaw,
+            243 => ActionOrigin::SwitchLeftGyroRoll,
+            244 => ActionOrigin::SwitchLeftGripLower,
+            245 => ActionOrigin::SwitchLeftGripUpper,
+            246 => ActionOrigin::SwitchRightGripLower,
+            247 => ActionOrigin::SwitchRightGripUpper,
+            248 => ActionOrigin::SwitchJoyConButtonN,
+            249 => ActionOrigin::SwitchJoyConButtonE,
+            250 => ActionOrigin::SwitchJoyConButtonS,
+            251 => ActionOrigin::SwitchJoyConButtonW,
+            252 => ActionOrigin::SwitchReserved15,
+            253 => ActionOrigin::SwitchReserved16,
+            254 => ActionOrigin::SwitchReserved17,
+            255 => ActionOrigin::SwitchReserved18,
+            256 => ActionOrigin::SwitchReserved19,
+            257 => ActionOrigin::SwitchReserved20,
+            258 => ActionOrigin::PS5X,
+            259 => ActionOrigin::PS5Circle,
+            260 => ActionOrigin::PS5Triangle,
+            261 => ActionOrigin::PS5Square,
+            262 => ActionOrigin::PS5LeftBumper,
+            263 => ActionOrigin::PS5RightBumper,
+            264 => ActionOrigin::PS5Option,
+            265 => ActionOrigin::PS5Create,
+            266 => ActionOrigin::PS5Mute,
+            267 => ActionOrigin::PS5LeftPadTouch,
+            268 => ActionOrigin::PS5LeftPadSwipe,
+            269 => ActionOrigin::PS5LeftPadClick,
+            270 => ActionOrigin::PS5LeftPadDPadNorth,
+            271 => ActionOrigin::PS5LeftPadDPadSouth,
+            272 => ActionOrigin::PS5LeftPadDPadWest,
+            273 => ActionOrigin::PS5LeftPadDPadEast,
+            274 => ActionOrigin::PS5RightPadTouch,
+            275 => ActionOrigin::PS5RightPadSwipe,
+            276 => ActionOrigin::PS5RightPadClick,
+            277 => ActionOrigin::PS5RightPadDPadNorth,
+            278 => ActionOrigin::PS5RightPadDPadSouth,
+            279 => ActionOrigin::PS5RightPadDPadWest,
+            280 => ActionOrigin::PS5RightPadDPadEast,
+            281 => ActionOrigin::PS5CenterPadTouch,
+            282 => ActionOrigin::PS5CenterPadSwipe,
+            283 => ActionOrigin::PS5CenterPadClick,
+            284 => ActionOrigin::PS5CenterPadDPadNorth,
+            285 => ActionOrigin::PS5CenterPadDPadSouth,
+            286 => ActionOrigin::PS5CenterPadDPadWest,
+            287 => ActionOrigin::PS5CenterPadDPadEast,
+            288 => ActionOrigin::PS5LeftTriggerPull,
+            289 => ActionOrigin::PS5LeftTriggerClick,
+            290 => ActionOrigin::PS5RightTriggerPull,
+            291 => ActionOrigin::PS5RightTriggerClick,
+            292 => ActionOrigin::PS5LeftStickMove,
+            293 => ActionOrigin::PS5LeftStickClick,
+            294 => ActionOrigin::PS5LeftStickDPadNorth,
+            295 => ActionOrigin::PS5LeftStickDPadSouth,
+            296 => ActionOrigin::PS5LeftStickDPadWest,
+            297 => ActionOrigin::PS5LeftStickDPadEast,
+            298 => ActionOrigin::PS5RightStickMove,
+            299 => ActionOrigin::PS5RightStickClick,
+            300 => ActionOrigin::PS5RightStickDPadNorth,
+            301 => ActionOrigin::PS5RightStickDPadSouth,
+            302 => ActionOrigin::PS5RightStickDPadWest,
+            303 => ActionOrigin::PS5RightStickDPadEast,
+            304 => ActionOrigin::PS5DPadNorth,
+            305 => ActionOrigin::PS5DPadSouth,
+            306 => ActionOrigin::PS5DPadWest,
+            307 => ActionOrigin::PS5DPadEast,
+            308 => ActionOrigin::PS5GyroMove,
+            309 => ActionOrigin::PS5GyroPitch,
+            310 => ActionOrigin::PS5GyroYaw,
+            311 => ActionOrigin::PS5GyroRoll,
+            312 => ActionOrigin::PS5DPadMove,
+            313 => ActionOrigin::PS5LeftGrip,
+            314 => ActionOrigin::PS5RightGrip,
+            315 => ActionOrigin::PS5LeftFn,
+            316 => ActionOrigin::PS5RightFn,
+            317 => ActionOrigin::PS5Reserved5,
+            318 => ActionOrigin::PS5Reserved6,
+            319 => ActionOrigin::PS5Reserved7,
+            320 => ActionOrigin::PS5Reserved8,
+            321 => ActionOrigin::PS5Reserved9,
+            322 => ActionOrigin::PS5Reserved10,
+            323 => ActionOrigin::PS5Reserved11,
+            324 => ActionOrigin::PS5Reserved12,
+            325 => ActionOrigin::PS5Reserved13,
+            326 => ActionOrigin::PS5Reserved14,
+            327 => ActionOrigin::PS5Reserved15,
+            328 => ActionOrigin::PS5Reserved16,
+            329 => ActionOrigin::PS5Reserved17,
+            330 => ActionOrigin::PS5Reserved18,
+            331 => ActionOrigin::PS5Reserved19,
+            332 => ActionOrigin::PS5Reserved20,
+            333 => ActionOrigin::SteamDeckA,
+            334 => ActionOrigin::SteamDeckB,
+            335 => ActionOrigin::SteamDeckX,
+            336 => ActionOrigin::SteamDeckY,
+            337 => ActionOrigin::SteamDeckL1,
+            338 => ActionOrigin::SteamDeckR1,
+            339 => ActionOrigin::SteamDeckMenu,
+            340 => ActionOrigin::SteamDeckView,
+            341 => ActionOrigin::SteamDeckLeftPadTouch,
+            342 => ActionOrigin::SteamDeckLeftPadSwipe,
+            343 => ActionOrigin::SteamDeckLeftPadClick,
+            344 => ActionOrigin::SteamDeckLeftPadDPadNorth,
+            345 => ActionOrigin::SteamDeckLeftPadDPadSouth,
+            346 => ActionOrigin::SteamDeckLeftPadDPadWest,
+            347 => ActionOrigin::SteamDeckLeftPadDPadEast,
+            348 => ActionOrigin::SteamDeckRightPadTouch,
+            349 => ActionOrigin::SteamDeckRightPadSwipe,
+            350 => ActionOrigin::SteamDeckRightPadClick,
+            351 => ActionOrigin::SteamDeckRightPadDPadNorth,
+            352 => ActionOrigin::SteamDeckRightPadDPadSouth,
+            353 => ActionOrigin::SteamDeckRightPadDPadWest,
+            354 => ActionOrigin::SteamDeckRightPadDPadEast,
+            355 => ActionOrigin::SteamDeckL2SoftPull,
+            356 => ActionOrigin::SteamDeckL2,
+            357 => ActionOrigin::SteamDeckR2SoftPull,
+            358 => ActionOrigin::SteamDeckR2,
+            359 => ActionOrigin::SteamDeckLeftStickMove,
+            360 => ActionOrigin::SteamDeckL3,
+            361 => ActionOrigin::SteamDeckLeftStickDPadNorth,
+            362 => ActionOrigin::SteamDeckLeftStickDPadSouth,
+            363 => ActionOrigin::SteamDeckLeftStickDPadWest,
+            364 => ActionOrigin::SteamDeckLeftStickDPadEast,
+            365 => ActionOrigin::SteamDeckLeftStickTouch,
+            366 => ActionOrigin::SteamDeckRightStickMove,
+            367 => ActionOrigin::SteamDeckR3,
+            368 => ActionOrigin::SteamDeckRightStickDPadNorth,
+            369 => ActionOrigin::SteamDeckRightStickDPadSouth,
+            370 => ActionOrigin::SteamDeckRightStickDPadWest,
+            371 => ActionOrigin::SteamDeckRightStickDPadEast,
+            372 => ActionOrigin::SteamDeckRightStickTouch,
+            373 => ActionOrigin::SteamDeckL4,
+            374 => ActionOrigin::SteamDeckR4,
+            375 => ActionOrigin::SteamDeckL5,
+            376 => ActionOrigin::SteamDeckR5,
+            377 => ActionOrigin::SteamDeckDPadMove,
+            378 => ActionOrigin::SteamDeckDPadNorth,
+            379 => ActionOrigin::SteamDeckDPadSouth,
+            380 => ActionOrigin::SteamDeckDPadWest,
+            381 => ActionOrigin::SteamDeckDPadEast,
+            382 => ActionOrigin::SteamDeckGyroMove,
+            383 => ActionOrigin::SteamDeckGyroPitch,
+            384 => ActionOrigin::SteamDeckGyroYaw,
+            385 => ActionOrigin::SteamDeckGyroRoll,
+            386 => ActionOrigin::SteamDeckReserved1,
+            387 => ActionOrigin::SteamDeckReserved2,
+            388 => ActionOrigin::SteamDeckReserved3,
+            389 => ActionOrigin::SteamDeckReserved4,
+            390 => ActionOrigin::SteamDeckReserved5,
+            391 => ActionOrigin::SteamDeckReserved6,
+            392 => ActionOrigin::SteamDeckReserved7,
+            393 => ActionOrigin::SteamDeckReserved8,
+            394 => ActionOrigin::SteamDeckReserved9,
+            395 => ActionOrigin::SteamDeckReserved10,
+            396 => ActionOrigin::SteamDeckReserved11,
+            397 => ActionOrigin::SteamDeckReserved12,
+            398 => ActionOrigin::SteamDeckReserved13,
+            399 => ActionOrigin::SteamDeckReserved14,
+            400 => ActionOrigin::SteamDeckReserved15,
+            401 => ActionOrigin::SteamDeckReserved16,
+            402 => ActionOrigin::SteamDeckReserved17,
+            403 => ActionOrigin::SteamDeckReserved18,
+            404 => ActionOrigin::SteamDeckReserved19,
+            405 => ActionOrigin::SteamDeckReserved20,
+            406 => ActionOrigin::HoripadM1,
+            407 => ActionOrigin::HoripadM2,
+            408 => ActionOrigin::HoripadL4,
+            409 => ActionOrigin::HoripadR4,
+            _ => return Err(InvalidActionOrigin(value)),
+        })
+    }
+}
+
+impl From<sys::EInputActionOrigin> for ActionOrigin {
+    /// Converts the raw origin, mapping values unknown to this version of
+    /// the crate to [`ActionOrigin::None`].
+    fn from(origin: sys::EInputActionOrigin) -> ActionOrigin {
+        ActionOrigin::try_from(origin as u32).unwrap_or(ActionOrigin::None)
+    }
+}
+
+impl From<ActionOrigin> for sys::EInputActionOrigin {
+    fn from(origin: ActionOrigin) -> sys::EInputActionOrigin {
+        match origin {
+            ActionOrigin::None => sys::EInputActionOrigin::k_EInputActionOrigin_None,
+            ActionOrigin::SteamControllerA => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_A
+            }
+            ActionOrigin::SteamControllerB => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_B
+            }
+            ActionOrigin::SteamControllerX => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_X
+            }
+            ActionOrigin::SteamControllerY => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Y
+            }
+            ActionOrigin::SteamControllerLeftBumper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftBumper
+            }
+            ActionOrigin::SteamControllerRightBumper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_RightBumper
+            }
+            ActionOrigin::SteamControllerLeftGrip => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftGrip
+            }
+            ActionOrigin::SteamControllerRightGrip => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_RightGrip
+            }
+            ActionOrigin::SteamControllerStart => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Start
+            }
+            ActionOrigin::SteamControllerBack => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Back
+            }
+            ActionOrigin::SteamControllerLeftPadTouch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftPad_Touch
+            }
+            ActionOrigin::SteamControllerLeftPadSwipe => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftPad_Swipe
+            }
+            ActionOrigin::SteamControllerLeftPadClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftPad_Click
+            }
+            ActionOrigin::SteamControllerLeftPadDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftPad_DPadNorth
+            }
+            ActionOrigin::SteamControllerLeftPadDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftPad_DPadSouth
+            }
+            ActionOrigin::SteamControllerLeftPadDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftPad_DPadWest
+            }
+            ActionOrigin::SteamControllerLeftPadDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftPad_DPadEast
+            }
+            ActionOrigin::SteamControllerRightPadTouch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_RightPad_Touch
+            }
+            ActionOrigin::SteamControllerRightPadSwipe => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_RightPad_Swipe
+            }
+            ActionOrigin::SteamControllerRightPadClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_RightPad_Click
+            }
+            ActionOrigin::SteamControllerRightPadDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_RightPad_DPadNorth
+            }
+            ActionOrigin::SteamControllerRightPadDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_RightPad_DPadSouth
+            }
+            ActionOrigin::SteamControllerRightPadDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_RightPad_DPadWest
+            }
+            ActionOrigin::SteamControllerRightPadDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_RightPad_DPadEast
+            }
+            ActionOrigin::SteamControllerLeftTriggerPull => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftTrigger_Pull
+            }
+            ActionOrigin::SteamControllerLeftTriggerClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftTrigger_Click
+            }
+            ActionOrigin::SteamControllerRightTriggerPull => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_RightTrigger_Pull
+            }
+            ActionOrigin::SteamControllerRightTriggerClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_RightTrigger_Click
+            }
+            ActionOrigin::SteamControllerLeftStickMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftStick_Move
+            }
+            ActionOrigin::SteamControllerLeftStickClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftStick_Click
+            }
+            ActionOrigin::SteamControllerLeftStickDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftStick_DPadNorth
+            }
+            ActionOrigin::SteamControllerLeftStickDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftStick_DPadSouth
+            }
+            ActionOrigin::SteamControllerLeftStickDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftStick_DPadWest
+            }
+            ActionOrigin::SteamControllerLeftStickDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_LeftStick_DPadEast
+            }
+            ActionOrigin::SteamControllerGyroMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Gyro_Move
+            }
+            ActionOrigin::SteamControllerGyroPitch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Gyro_Pitch
+            }
+            ActionOrigin::SteamControllerGyroYaw => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Gyro_Yaw
+            }
+            ActionOrigin::SteamControllerGyroRoll => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Gyro_Roll
+            }
+            ActionOrigin::SteamControllerReserved0 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Reserved0
+            }
+            ActionOrigin::SteamControllerReserved1 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Reserved1
+            }
+            ActionOrigin::SteamControllerReserved2 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Reserved2
+            }
+            ActionOrigin::SteamControllerReserved3 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Reserved3
+            }
+            ActionOrigin::SteamControllerReserved4 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Reserved4
+            }
+            ActionOrigin::SteamControllerReserved5 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Reserved5
+            }
+            ActionOrigin::SteamControllerReserved6 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Reserved6
+            }
+            ActionOrigin::SteamControllerReserved7 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Reserved7
+            }
+            ActionOrigin::SteamControllerReserved8 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Reserved8
+            }
+            ActionOrigin::SteamControllerReserved9 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Reserved9
+            }
+            ActionOrigin::SteamControllerReserved10 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamController_Reserved10
+            }
+            ActionOrigin::PS4X => sys::EInputActionOrigin::k_EInputActionOrigin_PS4_X,
+            ActionOrigin::PS4Circle => sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Circle,
+            ActionOrigin::PS4Triangle => sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Triangle,
+            ActionOrigin::PS4Square => sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Square,
+            ActionOrigin::PS4LeftBumper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftBumper
+            }
+            ActionOrigin::PS4RightBumper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightBumper
+            }
+            ActionOrigin::PS4Options => sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Options,
+            ActionOrigin::PS4Share => sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Share,
+            ActionOrigin::PS4LeftPadTouch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftPad_Touch
+            }
+            ActionOrigin::PS4LeftPadSwipe => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftPad_Swipe
+            }
+            ActionOrigin::PS4LeftPadClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftPad_Click
+            }
+            ActionOrigin::PS4LeftPadDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftPad_DPadNorth
+            }
+            ActionOrigin::PS4LeftPadDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftPad_DPadSouth
+            }
+            ActionOrigin::PS4LeftPadDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftPad_DPadWest
+            }
+            ActionOrigin::PS4LeftPadDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftPad_DPadEast
+            }
+            ActionOrigin::PS4RightPadTouch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightPad_Touch
+            }
+            ActionOrigin::PS4RightPadSwipe => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightPad_Swipe
+            }
+            ActionOrigin::PS4RightPadClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightPad_Click
+            }
+            ActionOrigin::PS4RightPadDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightPad_DPadNorth
+            }
+            ActionOrigin::PS4RightPadDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightPad_DPadSouth
+            }
+            ActionOrigin::PS4RightPadDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightPad_DPadWest
+            }
+            ActionOrigin::PS4RightPadDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightPad_DPadEast
+            }
+            ActionOrigin::PS4CenterPadTouch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_CenterPad_Touch
+            }
+            ActionOrigin::PS4CenterPadSwipe => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_CenterPad_Swipe
+            }
+            ActionOrigin::PS4CenterPadClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_CenterPad_Click
+            }
+            ActionOrigin::PS4CenterPadDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_CenterPad_DPadNorth
+            }
+            ActionOrigin::PS4CenterPadDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_CenterPad_DPadSouth
+            }
+            ActionOrigin::PS4CenterPadDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_CenterPad_DPadWest
+            }
+            ActionOrigin::PS4CenterPadDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_CenterPad_DPadEast
+            }
+            ActionOrigin::PS4LeftTriggerPull => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftTrigger_Pull
+            }
+            ActionOrigin::PS4LeftTriggerClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftTrigger_Click
+            }
+            ActionOrigin::PS4RightTriggerPull => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightTrigger_Pull
+            }
+            ActionOrigin::PS4RightTriggerClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightTrigger_Click
+            }
+            ActionOrigin::PS4LeftStickMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftStick_Move
+            }
+            ActionOrigin::PS4LeftStickClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftStick_Click
+            }
+            ActionOrigin::PS4LeftStickDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftStick_DPadNorth
+            }
+            ActionOrigin::PS4LeftStickDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftStick_DPadSouth
+            }
+            ActionOrigin::PS4LeftStickDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftStick_DPadWest
+            }
+            ActionOrigin::PS4LeftStickDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_LeftStick_DPadEast
+            }
+            ActionOrigin::PS4RightStickMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightStick_Move
+            }
+            ActionOrigin::PS4RightStickClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightStick_Click
+            }
+            ActionOrigin::PS4RightStickDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightStick_DPadNorth
+            }
+            ActionOrigin::PS4RightStickDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightStick_DPadSouth
+            }
+            ActionOrigin::PS4RightStickDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightStick_DPadWest
+            }
+            ActionOrigin::PS4RightStickDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_RightStick_DPadEast
+            }
+            ActionOrigin::PS4DPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_DPad_North
+            }
+            ActionOrigin::PS4DPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_DPad_South
+            }
+            ActionOrigin::PS4DPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_DPad_West
+            }
+            ActionOrigin::PS4DPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_DPad_East
+            }
+            ActionOrigin::PS4GyroMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Gyro_Move
+            }
+            ActionOrigin::PS4GyroPitch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Gyro_Pitch
+            }
+            ActionOrigin::PS4GyroYaw => sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Gyro_Yaw,
+            ActionOrigin::PS4GyroRoll => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Gyro_Roll
+            }
+            ActionOrigin::PS4DPadMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_DPad_Move
+            }
+            ActionOrigin::PS4Reserved1 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Reserved1
+            }
+            ActionOrigin::PS4Reserved2 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Reserved2
+            }
+            ActionOrigin::PS4Reserved3 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Reserved3
+            }
+            ActionOrigin::PS4Reserved4 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Reserved4
+            }
+            ActionOrigin::PS4Reserved5 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Reserved5
+            }
+            ActionOrigin::PS4Reserved6 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Reserved6
+            }
+            ActionOrigin::PS4Reserved7 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Reserved7
+            }
+            ActionOrigin::PS4Reserved8 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Reserved8
+            }
+            ActionOrigin::PS4Reserved9 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Reserved9
+            }
+            ActionOrigin::PS4Reserved10 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS4_Reserved10
+            }
+            ActionOrigin::XBoxOneA => sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_A,
+            ActionOrigin::XBoxOneB => sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_B,
+            ActionOrigin::XBoxOneX => sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_X,
+            ActionOrigin::XBoxOneY => sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_Y,
+            ActionOrigin::XBoxOneLeftBumper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_LeftBumper
+            }
+            ActionOrigin::XBoxOneRightBumper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_RightBumper
+            }
+            ActionOrigin::XBoxOneMenu => sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_Menu,
+            ActionOrigin::XBoxOneView => sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_View,
+            ActionOrigin::XBoxOneLeftTriggerPull => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_LeftTrigger_Pull
+            }
+            ActionOrigin::XBoxOneLeftTriggerClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_LeftTrigger_Click
+            }
+            ActionOrigin::XBoxOneRightTriggerPull => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_RightTrigger_Pull
+            }
+            ActionOrigin::XBoxOneRightTriggerClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_RightTrigger_Click
+            }
+            ActionOrigin::XBoxOneLeftStickMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_LeftStick_Move
+            }
+            ActionOrigin::XBoxOneLeftStickClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_LeftStick_Click
+            }
+            ActionOrigin::XBoxOneLeftStickDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_LeftStick_DPadNorth
+            }
+            ActionOrigin::XBoxOneLeftStickDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_LeftStick_DPadSouth
+            }
+            ActionOrigin::XBoxOneLeftStickDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_LeftStick_DPadWest
+            }
+            ActionOrigin::XBoxOneLeftStickDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_LeftStick_DPadEast
+            }
+            ActionOrigin::XBoxOneRightStickMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_RightStick_Move
+            }
+            ActionOrigin::XBoxOneRightStickClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_RightStick_Click
+            }
+            ActionOrigin::XBoxOneRightStickDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_RightStick_DPadNorth
+            }
+            ActionOrigin::XBoxOneRightStickDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_RightStick_DPadSouth
+            }
+            ActionOrigin::XBoxOneRightStickDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_RightStick_DPadWest
+            }
+            ActionOrigin::XBoxOneRightStickDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_RightStick_DPadEast
+            }
+            ActionOrigin::XBoxOneDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_DPad_North
+            }
+            ActionOrigin::XBoxOneDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_DPad_South
+            }
+            ActionOrigin::XBoxOneDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_DPad_West
+            }
+            ActionOrigin::XBoxOneDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_DPad_East
+            }
+            ActionOrigin::XBoxOneDPadMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_DPad_Move
+            }
+            ActionOrigin::XBoxOneLeftGripLower => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_LeftGrip_Lower
+            }
+            ActionOrigin::XBoxOneLeftGripUpper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_LeftGrip_Upper
+            }
+            ActionOrigin::XBoxOneRightGripLower => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_RightGrip_Lower
+            }
+            ActionOrigin::XBoxOneRightGripUpper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_RightGrip_Upper
+            }
+            ActionOrigin::XBoxOneShare => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_Share
+            }
+            ActionOrigin::XBoxOneReserved6 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_Reserved6
+            }
+            ActionOrigin::XBoxOneReserved7 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_Reserved7
+            }
+            ActionOrigin::XBoxOneReserved8 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_Reserved8
+            }
+            ActionOrigin::XBoxOneReserved9 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_Reserved9
+            }
+            ActionOrigin::XBoxOneReserved10 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBoxOne_Reserved10
+            }
+            ActionOrigin::XBox360A => sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_A,
+            ActionOrigin::XBox360B => sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_B,
+            ActionOrigin::XBox360X => sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_X,
+            ActionOrigin::XBox360Y => sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_Y,
+            ActionOrigin::XBox360LeftBumper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_LeftBumper
+            }
+            ActionOrigin::XBox360RightBumper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_RightBumper
+            }
+            ActionOrigin::XBox360Start => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_Start
+            }
+            ActionOrigin::XBox360Back => sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_Back,
+            ActionOrigin::XBox360LeftTriggerPull => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_LeftTrigger_Pull
+            }
+            ActionOrigin::XBox360LeftTriggerClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_LeftTrigger_Click
+            }
+            ActionOrigin::XBox360RightTriggerPull => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_RightTrigger_Pull
+            }
+            ActionOrigin::XBox360RightTriggerClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_RightTrigger_Click
+            }
+            ActionOrigin::XBox360LeftStickMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_LeftStick_Move
+            }
+            ActionOrigin::XBox360LeftStickClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_LeftStick_Click
+            }
+            ActionOrigin::XBox360LeftStickDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_LeftStick_DPadNorth
+            }
+            ActionOrigin::XBox360LeftStickDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_LeftStick_DPadSouth
+            }
+            ActionOrigin::XBox360LeftStickDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_LeftStick_DPadWest
+            }
+            ActionOrigin::XBox360LeftStickDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_LeftStick_DPadEast
+            }
+            ActionOrigin::XBox360RightStickMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_RightStick_Move
+            }
+            ActionOrigin::XBox360RightStickClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_RightStick_Click
+            }
+            ActionOrigin::XBox360RightStickDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_RightStick_DPadNorth
+            }
+            ActionOrigin::XBox360RightStickDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_RightStick_DPadSouth
+            }
+            ActionOrigin::XBox360RightStickDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_RightStick_DPadWest
+            }
+            ActionOrigin::XBox360RightStickDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_RightStick_DPadEast
+            }
+            ActionOrigin::XBox360DPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_DPad_North
+            }
+            ActionOrigin::XBox360DPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_DPad_South
+            }
+            ActionOrigin::XBox360DPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_DPad_West
+            }
+            ActionOrigin::XBox360DPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_DPad_East
+            }
+            ActionOrigin::XBox360DPadMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_DPad_Move
+            }
+            ActionOrigin::XBox360Reserved1 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_Reserved1
+            }
+            ActionOrigin::XBox360Reserved2 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_Reserved2
+            }
+            ActionOrigin::XBox360Reserved3 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_Reserved3
+            }
+            ActionOrigin::XBox360Reserved4 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_Reserved4
+            }
+            ActionOrigin::XBox360Reserved5 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_Reserved5
+            }
+            ActionOrigin::XBox360Reserved6 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_Reserved6
+            }
+            ActionOrigin::XBox360Reserved7 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_Reserved7
+            }
+            ActionOrigin::XBox360Reserved8 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_Reserved8
+            }
+            ActionOrigin::XBox360Reserved9 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_Reserved9
+            }
+            ActionOrigin::XBox360Reserved10 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_XBox360_Reserved10
+            }
+            ActionOrigin::SwitchA => sys::EInputActionOrigin::k_EInputActionOrigin_Switch_A,
+            ActionOrigin::SwitchB => sys::EInputActionOrigin::k_EInputActionOrigin_Switch_B,
+            ActionOrigin::SwitchX => sys::EInputActionOrigin::k_EInputActionOrigin_Switch_X,
+            ActionOrigin::SwitchY => sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Y,
+            ActionOrigin::SwitchLeftBumper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_LeftBumper
+            }
+            ActionOrigin::SwitchRightBumper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_RightBumper
+            }
+            ActionOrigin::SwitchPlus => sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Plus,
+            ActionOrigin::SwitchMinus => sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Minus,
+            ActionOrigin::SwitchCapture => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Capture
+            }
+            ActionOrigin::SwitchLeftTriggerPull => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_LeftTrigger_Pull
+            }
+            ActionOrigin::SwitchLeftTriggerClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_LeftTrigger_Click
+            }
+            ActionOrigin::SwitchRightTriggerPull => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_RightTrigger_Pull
+            }
+            ActionOrigin::SwitchRightTriggerClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_RightTrigger_Click
+            }
+            ActionOrigin::SwitchLeftStickMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_LeftStick_Move
+            }
+            ActionOrigin::SwitchLeftStickClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_LeftStick_Click
+            }
+            ActionOrigin::SwitchLeftStickDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_LeftStick_DPadNorth
+            }
+            ActionOrigin::SwitchLeftStickDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_LeftStick_DPadSouth
+            }
+            ActionOrigin::SwitchLeftStickDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_LeftStick_DPadWest
+            }
+            ActionOrigin::SwitchLeftStickDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_LeftStick_DPadEast
+            }
+            ActionOrigin::SwitchRightStickMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_RightStick_Move
+            }
+            ActionOrigin::SwitchRightStickClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_RightStick_Click
+            }
+            ActionOrigin::SwitchRightStickDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_RightStick_DPadNorth
+            }
+            ActionOrigin::SwitchRightStickDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_RightStick_DPadSouth
+            }
+            ActionOrigin::SwitchRightStickDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_RightStick_DPadWest
+            }
+            ActionOrigin::SwitchRightStickDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_RightStick_DPadEast
+            }
+            ActionOrigin::SwitchDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_DPad_North
+            }
+            ActionOrigin::SwitchDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_DPad_South
+            }
+            ActionOrigin::SwitchDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_DPad_West
+            }
+            ActionOrigin::SwitchDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_DPad_East
+            }
+            ActionOrigin::SwitchProGyroMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_ProGyro_Move
+            }
+            ActionOrigin::SwitchProGyroPitch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_ProGyro_Pitch
+            }
+            ActionOrigin::SwitchProGyroYaw => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_ProGyro_Yaw
+            }
+            ActionOrigin::SwitchProGyroRoll => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_ProGyro_Roll
+            }
+            ActionOrigin::SwitchDPadMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_DPad_Move
+            }
+            ActionOrigin::SwitchReserved1 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved1
+            }
+            ActionOrigin::SwitchReserved2 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved2
+            }
+            ActionOrigin::SwitchReserved3 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved3
+            }
+            ActionOrigin::SwitchReserved4 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved4
+            }
+            ActionOrigin::SwitchReserved5 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved5
+            }
+            ActionOrigin::SwitchReserved6 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved6
+            }
+            ActionOrigin::SwitchReserved7 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved7
+            }
+            ActionOrigin::SwitchReserved8 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved8
+            }
+            ActionOrigin::SwitchReserved9 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved9
+            }
+            ActionOrigin::SwitchReserved10 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved10
+            }
+            ActionOrigin::SwitchRightGyroMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_RightGyro_Move
+            }
+            ActionOrigin::SwitchRightGyroPitch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_RightGyro_Pitch
+            }
+            ActionOrigin::SwitchRightGyroYaw => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_RightGyro_Yaw
+            }
+            ActionOrigin::SwitchRightGyroRoll => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_RightGyro_Roll
+            }
+            ActionOrigin::SwitchLeftGyroMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_LeftGyro_Move
+            }
+            ActionOrigin::SwitchLeftGyroPitch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_LeftGyro_Pitch
+            }
+            ActionOrigin::SwitchLeftGyroYaw => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_LeftGyro_Yaw
+            }
+            ActionOrigin::SwitchLeftGyroRoll => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_LeftGyro_Roll
+            }
+            ActionOrigin::SwitchLeftGripLower => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_LeftGrip_Lower
+            }
+            ActionOrigin::SwitchLeftGripUpper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_LeftGrip_Upper
+            }
+            ActionOrigin::SwitchRightGripLower => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_RightGrip_Lower
+            }
+            ActionOrigin::SwitchRightGripUpper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_RightGrip_Upper
+            }
+            ActionOrigin::SwitchJoyConButtonN => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_JoyConButton_N
+            }
+            ActionOrigin::SwitchJoyConButtonE => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_JoyConButton_E
+            }
+            ActionOrigin::SwitchJoyConButtonS => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_JoyConButton_S
+            }
+            ActionOrigin::SwitchJoyConButtonW => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_JoyConButton_W
+            }
+            ActionOrigin::SwitchReserved15 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved15
+            }
+            ActionOrigin::SwitchReserved16 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved16
+            }
+            ActionOrigin::SwitchReserved17 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved17
+            }
+            ActionOrigin::SwitchReserved18 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved18
+            }
+            ActionOrigin::SwitchReserved19 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved19
+            }
+            ActionOrigin::SwitchReserved20 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_Switch_Reserved20
+            }
+            ActionOrigin::PS5X => sys::EInputActionOrigin::k_EInputActionOrigin_PS5_X,
+            ActionOrigin::PS5Circle => sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Circle,
+            ActionOrigin::PS5Triangle => sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Triangle,
+            ActionOrigin::PS5Square => sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Square,
+            ActionOrigin::PS5LeftBumper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftBumper
+            }
+            ActionOrigin::PS5RightBumper => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightBumper
+            }
+            ActionOrigin::PS5Option => sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Option,
+            ActionOrigin::PS5Create => sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Create,
+            ActionOrigin::PS5Mute => sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Mute,
+            ActionOrigin::PS5LeftPadTouch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftPad_Touch
+            }
+            ActionOrigin::PS5LeftPadSwipe => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftPad_Swipe
+            }
+            ActionOrigin::PS5LeftPadClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftPad_Click
+            }
+            ActionOrigin::PS5LeftPadDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftPad_DPadNorth
+            }
+            ActionOrigin::PS5LeftPadDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftPad_DPadSouth
+            }
+            ActionOrigin::PS5LeftPadDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftPad_DPadWest
+            }
+            ActionOrigin::PS5LeftPadDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftPad_DPadEast
+            }
+            ActionOrigin::PS5RightPadTouch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightPad_Touch
+            }
+            ActionOrigin::PS5RightPadSwipe => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightPad_Swipe
+            }
+            ActionOrigin::PS5RightPadClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightPad_Click
+            }
+            ActionOrigin::PS5RightPadDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightPad_DPadNorth
+            }
+            ActionOrigin::PS5RightPadDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightPad_DPadSouth
+            }
+            ActionOrigin::PS5RightPadDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightPad_DPadWest
+            }
+            ActionOrigin::PS5RightPadDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightPad_DPadEast
+            }
+            ActionOrigin::PS5CenterPadTouch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_CenterPad_Touch
+            }
+            ActionOrigin::PS5CenterPadSwipe => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_CenterPad_Swipe
+            }
+            ActionOrigin::PS5CenterPadClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_CenterPad_Click
+            }
+            ActionOrigin::PS5CenterPadDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_CenterPad_DPadNorth
+            }
+            ActionOrigin::PS5CenterPadDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_CenterPad_DPadSouth
+            }
+            ActionOrigin::PS5CenterPadDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_CenterPad_DPadWest
+            }
+            ActionOrigin::PS5CenterPadDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_CenterPad_DPadEast
+            }
+            ActionOrigin::PS5LeftTriggerPull => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftTrigger_Pull
+            }
+            ActionOrigin::PS5LeftTriggerClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftTrigger_Click
+            }
+            ActionOrigin::PS5RightTriggerPull => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightTrigger_Pull
+            }
+            ActionOrigin::PS5RightTriggerClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightTrigger_Click
+            }
+            ActionOrigin::PS5LeftStickMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftStick_Move
+            }
+            ActionOrigin::PS5LeftStickClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftStick_Click
+            }
+            ActionOrigin::PS5LeftStickDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftStick_DPadNorth
+            }
+            ActionOrigin::PS5LeftStickDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftStick_DPadSouth
+            }
+            ActionOrigin::PS5LeftStickDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftStick_DPadWest
+            }
+            ActionOrigin::PS5LeftStickDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftStick_DPadEast
+            }
+            ActionOrigin::PS5RightStickMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightStick_Move
+            }
+            ActionOrigin::PS5RightStickClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightStick_Click
+            }
+            ActionOrigin::PS5RightStickDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightStick_DPadNorth
+            }
+            ActionOrigin::PS5RightStickDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightStick_DPadSouth
+            }
+            ActionOrigin::PS5RightStickDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightStick_DPadWest
+            }
+            ActionOrigin::PS5RightStickDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightStick_DPadEast
+            }
+            ActionOrigin::PS5DPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_DPad_North
+            }
+            ActionOrigin::PS5DPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_DPad_South
+            }
+            ActionOrigin::PS5DPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_DPad_West
+            }
+            ActionOrigin::PS5DPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_DPad_East
+            }
+            ActionOrigin::PS5GyroMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Gyro_Move
+            }
+            ActionOrigin::PS5GyroPitch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Gyro_Pitch
+            }
+            ActionOrigin::PS5GyroYaw => sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Gyro_Yaw,
+            ActionOrigin::PS5GyroRoll => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Gyro_Roll
+            }
+            ActionOrigin::PS5DPadMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_DPad_Move
+            }
+            ActionOrigin::PS5LeftGrip => sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftGrip,
+            ActionOrigin::PS5RightGrip => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightGrip
+            }
+            ActionOrigin::PS5LeftFn => sys::EInputActionOrigin::k_EInputActionOrigin_PS5_LeftFn,
+            ActionOrigin::PS5RightFn => sys::EInputActionOrigin::k_EInputActionOrigin_PS5_RightFn,
+            ActionOrigin::PS5Reserved5 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved5
+            }
+            ActionOrigin::PS5Reserved6 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved6
+            }
+            ActionOrigin::PS5Reserved7 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved7
+            }
+            ActionOrigin::PS5Reserved8 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved8
+            }
+            ActionOrigin::PS5Reserved9 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved9
+            }
+            ActionOrigin::PS5Reserved10 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved10
+            }
+            ActionOrigin::PS5Reserved11 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved11
+            }
+            ActionOrigin::PS5Reserved12 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved12
+            }
+            ActionOrigin::PS5Reserved13 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved13
+            }
+            ActionOrigin::PS5Reserved14 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved14
+            }
+            ActionOrigin::PS5Reserved15 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved15
+            }
+            ActionOrigin::PS5Reserved16 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved16
+            }
+            ActionOrigin::PS5Reserved17 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved17
+            }
+            ActionOrigin::PS5Reserved18 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved18
+            }
+            ActionOrigin::PS5Reserved19 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved19
+            }
+            ActionOrigin::PS5Reserved20 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_PS5_Reserved20
+            }
+            ActionOrigin::SteamDeckA => sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_A,
+            ActionOrigin::SteamDeckB => sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_B,
+            ActionOrigin::SteamDeckX => sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_X,
+            ActionOrigin::SteamDeckY => sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Y,
+            ActionOrigin::SteamDeckL1 => sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_L1,
+            ActionOrigin::SteamDeckR1 => sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_R1,
+            ActionOrigin::SteamDeckMenu => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Menu
+            }
+            ActionOrigin::SteamDeckView => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_View
+            }
+            ActionOrigin::SteamDeckLeftPadTouch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_LeftPad_Touch
+            }
+            ActionOrigin::SteamDeckLeftPadSwipe => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_LeftPad_Swipe
+            }
+            ActionOrigin::SteamDeckLeftPadClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_LeftPad_Click
+            }
+            ActionOrigin::SteamDeckLeftPadDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_LeftPad_DPadNorth
+            }
+            ActionOrigin::SteamDeckLeftPadDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_LeftPad_DPadSouth
+            }
+            ActionOrigin::SteamDeckLeftPadDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_LeftPad_DPadWest
+            }
+            ActionOrigin::SteamDeckLeftPadDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_LeftPad_DPadEast
+            }
+            ActionOrigin::SteamDeckRightPadTouch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_RightPad_Touch
+            }
+            ActionOrigin::SteamDeckRightPadSwipe => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_RightPad_Swipe
+            }
+            ActionOrigin::SteamDeckRightPadClick => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_RightPad_Click
+            }
+            ActionOrigin::SteamDeckRightPadDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_RightPad_DPadNorth
+            }
+            ActionOrigin::SteamDeckRightPadDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_RightPad_DPadSouth
+            }
+            ActionOrigin::SteamDeckRightPadDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_RightPad_DPadWest
+            }
+            ActionOrigin::SteamDeckRightPadDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_RightPad_DPadEast
+            }
+            ActionOrigin::SteamDeckL2SoftPull => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_L2_SoftPull
+            }
+            ActionOrigin::SteamDeckL2 => sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_L2,
+            ActionOrigin::SteamDeckR2SoftPull => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_R2_SoftPull
+            }
+            ActionOrigin::SteamDeckR2 => sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_R2,
+            ActionOrigin::SteamDeckLeftStickMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_LeftStick_Move
+            }
+            ActionOrigin::SteamDeckL3 => sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_L3,
+            ActionOrigin::SteamDeckLeftStickDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_LeftStick_DPadNorth
+            }
+            ActionOrigin::SteamDeckLeftStickDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_LeftStick_DPadSouth
+            }
+            ActionOrigin::SteamDeckLeftStickDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_LeftStick_DPadWest
+            }
+            ActionOrigin::SteamDeckLeftStickDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_LeftStick_DPadEast
+            }
+            ActionOrigin::SteamDeckLeftStickTouch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_LeftStick_Touch
+            }
+            ActionOrigin::SteamDeckRightStickMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_RightStick_Move
+            }
+            ActionOrigin::SteamDeckR3 => sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_R3,
+            ActionOrigin::SteamDeckRightStickDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_RightStick_DPadNorth
+            }
+            ActionOrigin::SteamDeckRightStickDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_RightStick_DPadSouth
+            }
+            ActionOrigin::SteamDeckRightStickDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_RightStick_DPadWest
+            }
+            ActionOrigin::SteamDeckRightStickDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_RightStick_DPadEast
+            }
+            ActionOrigin::SteamDeckRightStickTouch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_RightStick_Touch
+            }
+            ActionOrigin::SteamDeckL4 => sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_L4,
+            ActionOrigin::SteamDeckR4 => sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_R4,
+            ActionOrigin::SteamDeckL5 => sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_L5,
+            ActionOrigin::SteamDeckR5 => sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_R5,
+            ActionOrigin::SteamDeckDPadMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_DPad_Move
+            }
+            ActionOrigin::SteamDeckDPadNorth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_DPad_North
+            }
+            ActionOrigin::SteamDeckDPadSouth => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_DPad_South
+            }
+            ActionOrigin::SteamDeckDPadWest => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_DPad_West
+            }
+            ActionOrigin::SteamDeckDPadEast => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_DPad_East
+            }
+            ActionOrigin::SteamDeckGyroMove => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Gyro_Move
+            }
+            ActionOrigin::SteamDeckGyroPitch => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Gyro_Pitch
+            }
+            ActionOrigin::SteamDeckGyroYaw => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Gyro_Yaw
+            }
+            ActionOrigin::SteamDeckGyroRoll => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Gyro_Roll
+            }
+            ActionOrigin::SteamDeckReserved1 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved1
+            }
+            ActionOrigin::SteamDeckReserved2 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved2
+            }
+            ActionOrigin::SteamDeckReserved3 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved3
+            }
+            ActionOrigin::SteamDeckReserved4 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved4
+            }
+            ActionOrigin::SteamDeckReserved5 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved5
+            }
+            ActionOrigin::SteamDeckReserved6 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved6
+            }
+            ActionOrigin::SteamDeckReserved7 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved7
+            }
+            ActionOrigin::SteamDeckReserved8 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved8
+            }
+            ActionOrigin::SteamDeckReserved9 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved9
+            }
+            ActionOrigin::SteamDeckReserved10 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved10
+            }
+            ActionOrigin::SteamDeckReserved11 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved11
+            }
+            ActionOrigin::SteamDeckReserved12 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved12
+            }
+            ActionOrigin::SteamDeckReserved13 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved13
+            }
+            ActionOrigin::SteamDeckReserved14 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved14
+            }
+            ActionOrigin::SteamDeckReserved15 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved15
+            }
+            ActionOrigin::SteamDeckReserved16 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved16
+            }
+            ActionOrigin::SteamDeckReserved17 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved17
+            }
+            ActionOrigin::SteamDeckReserved18 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved18
+            }
+            ActionOrigin::SteamDeckReserved19 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved19
+            }
+            ActionOrigin::SteamDeckReserved20 => {
+                sys::EInputActionOrigin::k_EInputActionOrigin_SteamDeck_Reserved20
+            }
+            ActionOrigin::HoripadM1 => sys::EInputActionOrigin::k_EInputActionOrigin_Horipad_M1,
+            ActionOrigin::HoripadM2 => sys::EInputActionOrigin::k_EInputActionOrigin_Horipad_M2,
+            ActionOrigin::HoripadL4 => sys::EInputActionOrigin::k_EInputActionOrigin_Horipad_L4,
+            ActionOrigin::HoripadR4 => sys::EInputActionOrigin::k_EInputActionOrigin_Horipad_R4,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn action_origin_round_trip() {
+        for value in 0..sys::EInputActionOrigin::k_EInputActionOrigin_Count as u32 {
+            let origin = ActionOrigin::try_from(value).unwrap();
+            assert_eq!(value, origin as u32);
+            assert_eq!(value, sys::EInputActionOrigin::from(origin) as u32);
+        }
+        assert_eq!(
+            Err(InvalidActionOrigin(32767)),
+            ActionOrigin::try_from(32767)
+        );
+        assert_eq!(
+            ActionOrigin::None,
+            ActionOrigin::from(sys::EInputActionOrigin::k_EInputActionOrigin_MaximumPossibleValue)
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use super::*;
+
+/// Access to the steam inventory interface
+pub struct Inventory {
+    pub(crate) inventory: *mut sys::ISteamInventory,
+    pub(crate) _inner: Arc<Inner>,
+}
+
+impl Inventory {
+    /// Starts retrieving all items in the current user's inventory.
+    ///
+    /// The returned result handle becomes ready once an [`InventoryResultReady`]
+    /// callback is posted for it. Returns `None` if the request couldn't be made.
+    pub fn get_all_items(&self) -> Option<InventoryResult> {
+        let mut handle = sys::k_SteamInventoryResultInvalid;
+        unsafe {
+            if sys::SteamAPI_ISteamInventory_GetAllItems(self.inventory, &mut handle) {
+                Some(InventoryResult(handle))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the status of a result handle.
+    ///
+    /// Returns `Err(SteamError::Pending)` while the result isn't ready yet.
+    pub fn get_result_status(&self, result: InventoryResult) -> SResult<()> {
+        unsafe {
+            crate::to_steam_result(sys::SteamAPI_ISteamInventory_GetResultStatus(
+                self.inventory,
+                result.0,
+            ))
+        }
+    }
+
+    /// Returns the items contained in a ready result handle.
+    ///
+    /// Returns `None` if the result isn't ready or the handle is invalid.
+    pub fn get_result_items(&self, result: InventoryResult) -> Option<Vec<ItemDetails>> {
+        unsafe {
+            let mut count = 0u32;
+            if !sys::SteamAPI_ISteamInventory_GetResultItems(
+                self.inventory,
+                result.0,
+                std::ptr::null_mut(),
+                &mut count,
+            ) {
+                return None;
+            }
+            let mut items = Vec::with_capacity(count as usize);
+            if !sys::SteamAPI_ISteamInventory_GetResultItems(
+                self.inventory,
+                result.0,
+                items.as_mut_ptr(),
+                &mut count,
+            ) {
+                return None;
+            }
+            items.set_len(count as usize);
+            Some(
+                items
+                    .into_iter()
+                    .map(|item: sys::SteamItemDetails_t| ItemDetails {
+                        item_id: ItemInstanceId(item.m_itemId),
+                        definition: ItemDef(item.m_iDefinition),
+                        quantity: item.m_unQuantity,
+                        flags: ItemFlags::from_bits_truncate(item.m_unFlags),
+                    })
+                    .collect(),
+            )
+        }
+    }
+
+    /// Destroys a result handle and frees its associated memory.
+    pub fn destroy_result(&self, result: InventoryResult) {
+        unsafe {
+            sys::SteamAPI_ISteamInventory_DestroyResult(self.inventory, result.0);
+        }
+    }
+
+    /// Consumes `quantity` of an item from the user's inventory.
+    ///
+    /// If the quantity reaches zero the item is removed entirely. The returned
+    /// result handle contains the updated item once ready.
+    pub fn consume_item(&self, item: ItemInstanceId, quantity: u32) -> Option<InventoryResult> {
+        let mut handle = sys::k_SteamInventoryResultInvalid;
+        unsafe {
+            if sys::SteamAPI_ISteamInventory_ConsumeItem(
+                self.inventory,
+                &mut handle,
+                item.0,
+                quantity,
+            ) {
+                Some(InventoryResult(handle))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Grants the items in `generate` in exchange for the items in `destroy`.
+    ///
+    /// The exchange has to match a recipe defined in the item definition of
+    /// the generated item. Only a single item definition can be generated at
+    /// a time.
+    pub fn exchange_items(
+        &self,
+        generate: &[(ItemDef, u32)],
+        destroy: &[(ItemInstanceId, u32)],
+    ) -> Option<InventoryResult> {
+        let (generate_defs, generate_quantities): (Vec<_>, Vec<_>) = generate
+            .iter()
+            .map(|&(def, quantity)| (def.0, quantity))
+            .unzip();
+        let (destroy_ids, destroy_quantities): (Vec<_>, Vec<_>) = destroy
+            .iter()
+            .map(|&(id, quantity)| (id.0, quantity))
+            .unzip();
+        let mut handle = sys::k_SteamInventoryResultInvalid;
+        unsafe {
+            if sys::SteamAPI_ISteamInventory_ExchangeItems(
+                self.inventory,
+                &mut handle,
+                generate_defs.as_ptr(),
+                generate_quantities.as_ptr(),
+                generate_defs.len() as u32,
+                destroy_ids.as_ptr(),
+                destroy_quantities.as_ptr(),
+                destroy_ids.len() as u32,
+            ) {
+                Some(InventoryResult(handle))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// A handle to the result of an asynchronous inventory request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InventoryResult(pub(crate) sys::SteamInventoryResult_t);
+
+impl InventoryResult {
+    /// Returns the raw value of the result handle
+    pub fn raw(&self) -> i32 {
+        self.0
+    }
+}
+
+/// The id of a single item instance in a user's inventory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ItemInstanceId(pub u64);
+
+/// The id of an item definition, as configured for the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ItemDef(pub i32);
+
+bitflags! {
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[repr(C)]
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+    pub struct ItemFlags: u16 {
+        /// The item is account-bound and can't be traded or given away.
+        const NO_TRADE = sys::ESteamItemFlags::k_ESteamItemNoTrade.0 as _;
+        /// The item has been destroyed, traded away, expired or otherwise invalidated.
+        const REMOVED = sys::ESteamItemFlags::k_ESteamItemRemoved.0 as _;
+        /// The item quantity has been decreased by one by `consume_item`.
+        const CONSUMED = sys::ESteamItemFlags::k_ESteamItemConsumed.0 as _;
+    }
+}
+
+/// An item contained in an inventory result
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ItemDetails {
+    pub item_id: ItemInstanceId,
+    pub definition: ItemDef,
+    pub quantity: u16,
+    pub flags: ItemFlags,
+}
+
+/// Called when an inventory result handle has finished processing.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InventoryResultReady {
+    pub handle: InventoryResult,
+    pub result: SResult<()>,
+}
+
+impl_callback!(cb: SteamInventoryResultReady_t => InventoryResultReady {
+    Self {
+        handle: InventoryResult(cb.m_handle),
+        result: crate::to_steam_result(cb.m_result),
+    }
+});
@@ -23,16 +23,24 @@ use std::sync::{Arc, Mutex, Weak};
 use serde::{Deserialize, Serialize};
 
 pub use crate::app::*;
+pub use crate::call_result::*;
 pub use crate::callback::*;
 pub use crate::error::*;
 pub use crate::friends::*;
+pub use crate::http::*;
 pub use crate::input::*;
+pub use crate::input_action_origin::*;
+pub use crate::inventory::*;
 pub use crate::matchmaking::*;
 pub use crate::matchmaking_servers::*;
+pub use crate::music::*;
+pub use crate::music_remote::*;
 pub use crate::networking::*;
+pub use crate::parties::*;
 pub use crate::remote_play::*;
 pub use crate::remote_storage::*;
 pub use crate::server::*;
+pub use crate::server_stats::*;
 pub use crate::timeline::*;
 pub use crate::ugc::*;
 pub use crate::user::*;
@@ -42,21 +50,30 @@ pub use crate::utils::*;
 #[macro_use]
 mod callback;
 mod app;
+mod call_result;
 mod error;
 mod friends;
+mod http;
 mod input;
+mod input_action_origin;
+mod inventory;
 mod matchmaking;
 mod matchmaking_servers;
+mod music;
+mod music_remote;
 mod networking;
 pub mod networking_messages;
 pub mod networking_sockets;
 mod networking_sockets_callback;
 pub mod networking_types;
 pub mod networking_utils;
+mod parties;
+pub mod prelude;
 mod remote_play;
 mod remote_storage;
 pub mod screenshots;
 mod server;
+mod server_stats;
 pub mod timeline;
 mod ugc;
 mod user;
@@ -412,6 +429,18 @@ impl Client {
         }
     }
 
+    /// Returns an accessor to the steam HTTP interface
+    pub fn http(&self) -> Http {
+        unsafe {
+            let http = sys::SteamAPI_SteamHTTP_v003();
+            debug_assert!(!http.is_null());
+            Http {
+                http,
+                inner: self.inner.clone(),
+            }
+        }
+    }
+
     /// Returns an accessor to the steam input interface
     pub fn input(&self) -> Input {
         unsafe {
@@ -424,6 +453,18 @@ impl Client {
         }
     }
 
+    /// Returns an accessor to the steam inventory interface
+    pub fn inventory(&self) -> Inventory {
+        unsafe {
+            let inventory = sys::SteamAPI_SteamInventory_v003();
+            debug_assert!(!inventory.is_null());
+            Inventory {
+                inventory,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
     /// Returns an accessor to the steam user interface
     pub fn user(&self) -> User {
         unsafe {
@@ -431,7 +472,7 @@ impl Client {
             debug_assert!(!user.is_null());
             User {
                 user,
-                _inner: self.inner.clone(),
+                inner: self.inner.clone(),
             }
         }
     }
@@ -448,6 +489,42 @@ impl Client {
         }
     }
 
+    /// Returns an accessor to the steam music interface
+    pub fn music(&self) -> Music {
+        unsafe {
+            let music = sys::SteamAPI_SteamMusic_v001();
+            debug_assert!(!music.is_null());
+            Music {
+                music,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam music remote interface
+    pub fn music_remote(&self) -> MusicRemote {
+        unsafe {
+            let music_remote = sys::SteamAPI_SteamMusicRemote_v001();
+            debug_assert!(!music_remote.is_null());
+            MusicRemote {
+                music_remote,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam parties interface
+    pub fn parties(&self) -> Parties {
+        unsafe {
+            let parties = sys::SteamAPI_SteamParties_v002();
+            debug_assert!(!parties.is_null());
+            Parties {
+                parties,
+                inner: self.inner.clone(),
+            }
+        }
+    }
+
     /// Returns an accessor to the steam remote play interface
     pub fn remote_play(&self) -> RemotePlay {
         unsafe {
@@ -579,6 +656,16 @@ impl Drop for Manager {
 pub struct SteamId(pub(crate) u64);
 
 impl SteamId {
+    /// An invalid steam id, useful as a sentinel value
+    pub const INVALID: SteamId = SteamId(0);
+
+    /// Returns an invalid steam id.
+    ///
+    /// Same as [`SteamId::INVALID`].
+    pub fn invalid() -> SteamId {
+        SteamId::INVALID
+    }
+
     /// Creates a `SteamId` from a raw 64 bit value.
     ///
     /// May be useful for deserializing steam ids from
@@ -596,6 +683,9 @@ impl SteamId {
     }
 
     /// Returns whether or not this Steam ID is invalid, which is when `account_type` is `k_EAccountTypeInvalid`.
+    ///
+    /// This only checks the account type, use [`is_valid`](Self::is_valid) for a
+    /// full check of all fields. An id can be neither invalid nor valid.
     pub fn is_invalid(&self) -> bool {
         unsafe {
             let bits = sys::CSteamID_SteamID_t {
@@ -606,6 +696,81 @@ impl SteamId {
         }
     }
 
+    /// Returns whether or not this Steam ID is a valid id of its account type,
+    /// e.g. an individual account with a non-zero account id in a known universe.
+    ///
+    /// This is stricter than `!is_invalid()`, which only checks the account type.
+    pub fn is_valid(&self) -> bool {
+        let account_type = self.account_type();
+        if account_type == AccountType::Invalid || self.universe() == Universe::Invalid {
+            return false;
+        }
+        let account_id = self.account_id().raw();
+        match account_type {
+            AccountType::Individual => {
+                // Instances above 4 (`k_unSteamUserWebInstance`) are never handed out
+                account_id != 0 && self.account_instance() <= 4
+            }
+            AccountType::Clan => account_id != 0 && self.account_instance() == 0,
+            AccountType::GameServer => account_id != 0,
+            _ => true,
+        }
+    }
+
+    /// Returns the type of account this steam id belongs to
+    pub fn account_type(&self) -> AccountType {
+        let account_type = unsafe {
+            let bits = sys::CSteamID_SteamID_t {
+                m_unAll64Bits: self.0,
+            };
+            bits.m_comp.m_EAccountType()
+        };
+        match account_type {
+            1 => AccountType::Individual,
+            2 => AccountType::Multiseat,
+            3 => AccountType::GameServer,
+            4 => AccountType::AnonGameServer,
+            5 => AccountType::Pending,
+            6 => AccountType::ContentServer,
+            7 => AccountType::Clan,
+            8 => AccountType::Chat,
+            9 => AccountType::ConsoleUser,
+            10 => AccountType::AnonUser,
+            _ => AccountType::Invalid,
+        }
+    }
+
+    /// Returns the universe this steam id belongs to
+    pub fn universe(&self) -> Universe {
+        let universe = unsafe {
+            let bits = sys::CSteamID_SteamID_t {
+                m_unAll64Bits: self.0,
+            };
+            // `m_EUniverse()` transmutes the bits into `EUniverse`, which isn't
+            // sound for unknown universes, so the raw bits are read instead
+            bits.m_comp._bitfield_1.get(56, 8)
+        };
+        match universe {
+            1 => Universe::Public,
+            2 => Universe::Beta,
+            3 => Universe::Internal,
+            4 => Universe::Dev,
+            _ => Universe::Invalid,
+        }
+    }
+
+    /// Returns the account instance of this steam id
+    ///
+    /// Regular user accounts use instance `1`.
+    pub fn account_instance(&self) -> u32 {
+        unsafe {
+            let bits = sys::CSteamID_SteamID_t {
+                m_unAll64Bits: self.0,
+            };
+            bits.m_comp.m_unAccountInstance()
+        }
+    }
+
     /// Returns the account id for this steam id
     pub fn account_id(&self) -> AccountId {
         unsafe {
@@ -624,6 +789,138 @@ impl SteamId {
     }
 }
 
+impl From<sys::CSteamID> for SteamId {
+    fn from(id: sys::CSteamID) -> SteamId {
+        unsafe { SteamId(id.m_steamid.m_unAll64Bits) }
+    }
+}
+
+impl From<SteamId> for sys::CSteamID {
+    fn from(id: SteamId) -> sys::CSteamID {
+        sys::CSteamID {
+            m_steamid: sys::CSteamID_SteamID_t {
+                m_unAll64Bits: id.0,
+            },
+        }
+    }
+}
+
+impl fmt::Display for SteamId {
+    /// Formats the steam id as its 64 bit decimal value, e.g. `76561197960287930`
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for SteamId {
+    type Err = ParseSteamIdError;
+
+    /// Parses either the 64 bit decimal form, e.g. `76561197960287930`, or the
+    /// legacy `STEAM_X:Y:Z` form of an individual account.
+    fn from_str(s: &str) -> Result<SteamId, ParseSteamIdError> {
+        let Some(legacy) = s.strip_prefix("STEAM_") else {
+            return s.parse().map(SteamId).map_err(|_| ParseSteamIdError);
+        };
+
+        let mut parts = legacy.split(':');
+        let (Some(universe), Some(last_bit), Some(rest), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(ParseSteamIdError);
+        };
+        let universe: u64 = universe.parse().map_err(|_| ParseSteamIdError)?;
+        let last_bit: u32 = last_bit.parse().map_err(|_| ParseSteamIdError)?;
+        let rest: u32 = rest.parse().map_err(|_| ParseSteamIdError)?;
+        if last_bit > 1 || rest > u32::MAX >> 1 || universe > 0xFF {
+            return Err(ParseSteamIdError);
+        }
+
+        // Old tools write universe 0 for what is the public universe
+        let universe = if universe == 0 { 1 } else { universe };
+        let account_id = (rest << 1) | last_bit;
+        Ok(SteamId(
+            (universe << 56)
+                | ((sys::EAccountType::k_EAccountTypeIndividual as u64) << 52)
+                | ((sys::k_unSteamUserDefaultInstance as u64) << 32)
+                | account_id as u64,
+        ))
+    }
+}
+
+/// Returned when a string can't be parsed as a [`SteamId`]
+#[derive(Debug, Error)]
+#[error("string could not be parsed as a steam id")]
+pub struct ParseSteamIdError;
+
+/// The type of account a [`SteamId`] belongs to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AccountType {
+    Invalid,
+    /// A single user account
+    Individual,
+    /// A multiseat account, e.g. a cybercafe
+    Multiseat,
+    /// A persistent game server account
+    GameServer,
+    /// An anonymous game server account
+    AnonGameServer,
+    /// A pending account
+    Pending,
+    /// A valve internal content server account
+    ContentServer,
+    /// A steam group
+    Clan,
+    /// A steam group chat or lobby
+    Chat,
+    /// A fake steam id for a local PSN account on PS3 or Live account on 360
+    ConsoleUser,
+    /// An anonymous user account, used to create an account or reset a password
+    AnonUser,
+}
+
+impl From<AccountType> for sys::EAccountType {
+    fn from(account_type: AccountType) -> sys::EAccountType {
+        match account_type {
+            AccountType::Invalid => sys::EAccountType::k_EAccountTypeInvalid,
+            AccountType::Individual => sys::EAccountType::k_EAccountTypeIndividual,
+            AccountType::Multiseat => sys::EAccountType::k_EAccountTypeMultiseat,
+            AccountType::GameServer => sys::EAccountType::k_EAccountTypeGameServer,
+            AccountType::AnonGameServer => sys::EAccountType::k_EAccountTypeAnonGameServer,
+            AccountType::Pending => sys::EAccountType::k_EAccountTypePending,
+            AccountType::ContentServer => sys::EAccountType::k_EAccountTypeContentServer,
+            AccountType::Clan => sys::EAccountType::k_EAccountTypeClan,
+            AccountType::Chat => sys::EAccountType::k_EAccountTypeChat,
+            AccountType::ConsoleUser => sys::EAccountType::k_EAccountTypeConsoleUser,
+            AccountType::AnonUser => sys::EAccountType::k_EAccountTypeAnonUser,
+        }
+    }
+}
+
+/// The steam universe a [`SteamId`] belongs to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Universe {
+    Invalid,
+    /// The public universe, used by all regular accounts
+    Public,
+    Beta,
+    Internal,
+    Dev,
+}
+
+impl From<Universe> for sys::EUniverse {
+    fn from(universe: Universe) -> sys::EUniverse {
+        match universe {
+            Universe::Invalid => sys::EUniverse::k_EUniverseInvalid,
+            Universe::Public => sys::EUniverse::k_EUniversePublic,
+            Universe::Beta => sys::EUniverse::k_EUniverseBeta,
+            Universe::Internal => sys::EUniverse::k_EUniverseInternal,
+            Universe::Dev => sys::EUniverse::k_EUniverseDev,
+        }
+    }
+}
+
 /// A user's account id
 #[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -731,5 +1028,33 @@ mod tests {
 
         let steamid = SteamId(76561198174976054);
         assert_eq!("STEAM_0:0:107355163", steamid.steamid32());
+
+        assert!(steamid.is_valid());
+        assert_eq!(AccountType::Individual, steamid.account_type());
+        assert_eq!(Universe::Public, steamid.universe());
+        assert_eq!(1, steamid.account_instance());
+
+        assert!(!SteamId::INVALID.is_valid());
+        assert!(SteamId::invalid().is_invalid());
+        assert_eq!(Universe::Invalid, SteamId::INVALID.universe());
+    }
+
+    #[test]
+    fn steamid_parse_test() {
+        let steamid = SteamId(76561198040894045);
+        assert_eq!("76561198040894045", steamid.to_string());
+        assert_eq!(Ok(steamid), steamid.to_string().parse().map_err(|_| ()));
+        assert_eq!(Ok(steamid), steamid.steamid32().parse().map_err(|_| ()));
+
+        let steamid = SteamId(76561198174976054);
+        assert_eq!(Ok(steamid), steamid.steamid32().parse().map_err(|_| ()));
+        assert_eq!(
+            Ok(steamid),
+            "STEAM_1:0:107355163".parse::<SteamId>().map_err(|_| ())
+        );
+
+        assert!("STEAM_0:2:107355163".parse::<SteamId>().is_err());
+        assert!("STEAM_0:0".parse::<SteamId>().is_err());
+        assert!("7656119817497605a".parse::<SteamId>().is_err());
     }
 }
@@ -23,6 +23,17 @@ pub enum LobbyType {
     Invisible,
 }
 
+impl From<LobbyType> for sys::ELobbyType {
+    fn from(ty: LobbyType) -> Self {
+        match ty {
+            LobbyType::Private => sys::ELobbyType::k_ELobbyTypePrivate,
+            LobbyType::FriendsOnly => sys::ELobbyType::k_ELobbyTypeFriendsOnly,
+            LobbyType::Public => sys::ELobbyType::k_ELobbyTypePublic,
+            LobbyType::Invisible => sys::ELobbyType::k_ELobbyTypeInvisible,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LobbyId(pub(crate) u64);
@@ -46,6 +57,15 @@ impl LobbyId {
 }
 
 impl Matchmaking {
+    /// Requests a filtered list of lobbies to join.
+    ///
+    /// The filters set up with the `add_request_lobby_list_*` and
+    /// `set_request_lobby_list_*` methods, or with
+    /// [`set_lobby_list_filter`](Matchmaking::set_lobby_list_filter), apply to this request
+    /// and are reset afterwards.
+    ///
+    /// The callback receives the ids of the matching lobbies, sorted by distance
+    /// and then by any near value filters.
     pub fn request_lobby_list<F>(&self, cb: F)
     where
         F: FnOnce(SResult<Vec<LobbyId>>) + 'static + Send,
@@ -89,14 +109,8 @@ impl Matchmaking {
     {
         assert!(max_members <= 250); // Steam API limits
         unsafe {
-            let ty = match ty {
-                LobbyType::Private => sys::ELobbyType::k_ELobbyTypePrivate,
-                LobbyType::FriendsOnly => sys::ELobbyType::k_ELobbyTypeFriendsOnly,
-                LobbyType::Public => sys::ELobbyType::k_ELobbyTypePublic,
-                LobbyType::Invisible => sys::ELobbyType::k_ELobbyTypeInvisible,
-            };
             let api_call =
-                sys::SteamAPI_ISteamMatchmaking_CreateLobby(self.mm, ty, max_members as _);
+                sys::SteamAPI_ISteamMatchmaking_CreateLobby(self.mm, ty.into(), max_members as _);
             register_call_result::<sys::LobbyCreated_t, _>(
                 &self.inner,
                 api_call,
@@ -234,8 +248,21 @@ impl Matchmaking {
             CStr::from_ptr(data)
         }
         .to_str()
-        .map(str::to_owned)
         .ok()
+        .filter(|s| !s.is_empty())
+        .map(str::to_owned)
+    }
+
+    /// Refreshes the metadata of a lobby you're not in.
+    ///
+    /// This never needs to be called for lobbies you're a member of, as their
+    /// metadata is always kept up to date.
+    ///
+    /// Triggers a LobbyDataUpdate callback once the metadata has been received.
+    ///
+    /// Returns false if the request could not be sent.
+    pub fn request_lobby_data(&self, lobby: LobbyId) -> bool {
+        unsafe { sys::SteamAPI_ISteamMatchmaking_RequestLobbyData(self.mm, lobby.0) }
     }
 
     /// Exits the passed lobby
@@ -258,6 +285,23 @@ impl Matchmaking {
         }
     }
 
+    /// Sets the maximum number of players that can join the lobby.
+    ///
+    /// Returns true on success, false if the current user doesn't own the lobby.
+    pub fn set_lobby_member_limit(&self, lobby: LobbyId, max_members: u32) -> bool {
+        assert!(max_members <= 250); // Steam API limits
+        unsafe {
+            sys::SteamAPI_ISteamMatchmaking_SetLobbyMemberLimit(self.mm, lobby.0, max_members as _)
+        }
+    }
+
+    /// Changes the visibility of the lobby.
+    ///
+    /// Returns true on success, false if the current user doesn't own the lobby.
+    pub fn set_lobby_type(&self, lobby: LobbyId, ty: LobbyType) -> bool {
+        unsafe { sys::SteamAPI_ISteamMatchmaking_SetLobbyType(self.mm, lobby.0, ty.into()) }
+    }
+
     /// Returns the steam id of the current owner of the passed lobby
     pub fn lobby_owner(&self, lobby: LobbyId) -> SteamId {
         unsafe {
@@ -267,6 +311,16 @@ impl Matchmaking {
         }
     }
 
+    /// Transfers ownership of the lobby to another member of the lobby.
+    ///
+    /// Triggers a LobbyDataUpdate callback for all lobby members.
+    ///
+    /// Returns true on success, false if the current user doesn't own the lobby
+    /// or the new owner isn't a member of it.
+    pub fn set_lobby_owner(&self, lobby: LobbyId, new_owner: SteamId) -> bool {
+        unsafe { sys::SteamAPI_ISteamMatchmaking_SetLobbyOwner(self.mm, lobby.0, new_owner.0) }
+    }
+
     /// Returns the number of players in a lobby.
     ///
     /// Useful if you are not currently in the lobby
@@ -278,6 +332,8 @@ impl Matchmaking {
     }
 
     /// Returns a list of members currently in the lobby
+    ///
+    /// The current user must be in the lobby to retrieve its members.
     pub fn lobby_members(&self, lobby: LobbyId) -> Vec<SteamId> {
         unsafe {
             let count = sys::SteamAPI_ISteamMatchmaking_GetNumLobbyMembers(self.mm, lobby.0);
@@ -344,16 +400,14 @@ impl Matchmaking {
         }
     }
 
-    /// Gets the data from a lobby chat message after receiving a `LobbyChatMsg_t` callback.
+    /// Reads a lobby chat message into `buffer` and returns the part of the buffer
+    /// holding the message.
     ///
-    /// # Parameters
-    /// - `lobby`: The Steam ID of the lobby to get the chat message from.
-    /// - `chat_id`: The index of the chat entry in the lobby.
-    /// - `buffer`: Buffer to save retrieved message data to. The buffer should be no
-    /// more than 4 Kilobytes.
+    /// `chat_id` comes from a [`LobbyChatMsg`] callback and is only valid while that
+    /// callback is being handled. Messages longer than `buffer` are truncated, the
+    /// largest possible message is 4 Kilobytes.
     ///
-    /// # Returns
-    /// Returns `&[u8]` A resliced byte array of the message buffer
+    /// Returns an empty slice if the entry could not be read.
     pub fn get_lobby_chat_entry<'a>(
         &self,
         lobby: LobbyId,
@@ -374,7 +428,7 @@ impl Matchmaking {
                 buffer.len() as _,
                 &mut chat_type,
             );
-            return &buffer[0..len as usize];
+            return &buffer[0..len.max(0) as usize];
         }
     }
     /// Adds a string comparison filter to the lobby list request.
@@ -503,6 +557,23 @@ impl Matchmaking {
         }
         self
     }
+    /// Adds a compatible members filter to the lobby list request.
+    ///
+    /// This method adds a filter that only includes lobbies containing users
+    /// that the members of the given lobby have played with before.
+    ///
+    /// # Arguments
+    ///
+    /// * `lobby`: The lobby whose members should be compared against.
+    ///
+    pub fn add_request_lobby_list_compatible_members_filter(&self, lobby: LobbyId) -> &Self {
+        unsafe {
+            sys::SteamAPI_ISteamMatchmaking_AddRequestLobbyListCompatibleMembersFilter(
+                self.mm, lobby.0,
+            );
+        }
+        self
+    }
 
     /// Sets filters for the lobbies to be returned from [`request_lobby_list`].
     ///
@@ -637,6 +708,104 @@ impl Matchmaking {
             }
         }
     }
+
+    /// Returns the game servers in the user's favorites and history lists.
+    pub fn favorite_games(&self) -> Vec<FavoriteGame> {
+        unsafe {
+            let count = sys::SteamAPI_ISteamMatchmaking_GetFavoriteGameCount(self.mm);
+            let mut games = Vec::with_capacity(count.max(0) as usize);
+            for idx in 0..count {
+                let mut app_id = 0;
+                let mut ip = 0;
+                let mut conn_port = 0;
+                let mut query_port = 0;
+                let mut flags = 0;
+                let mut last_played = 0;
+                if sys::SteamAPI_ISteamMatchmaking_GetFavoriteGame(
+                    self.mm,
+                    idx,
+                    &mut app_id,
+                    &mut ip,
+                    &mut conn_port,
+                    &mut query_port,
+                    &mut flags,
+                    &mut last_played,
+                ) {
+                    games.push(FavoriteGame {
+                        app_id: AppId(app_id),
+                        server_addr: SocketAddrV4::new(Ipv4Addr::from_bits(ip), conn_port),
+                        query_port,
+                        flags: FavoriteFlags::from_bits_truncate(flags),
+                        last_played_on_server: last_played,
+                    });
+                }
+            }
+            games
+        }
+    }
+
+    /// Adds the game server to the user's favorites or history list.
+    ///
+    /// If the server is already in the list its flags and last played
+    /// time are updated instead.
+    ///
+    /// Returns the index of the server in the list.
+    pub fn add_favorite_game(&self, game: &FavoriteGame) -> u32 {
+        unsafe {
+            sys::SteamAPI_ISteamMatchmaking_AddFavoriteGame(
+                self.mm,
+                game.app_id.0,
+                game.server_addr.ip().to_bits(),
+                game.server_addr.port(),
+                game.query_port,
+                game.flags.bits(),
+                game.last_played_on_server,
+            ) as u32
+        }
+    }
+
+    /// Removes the game server from the user's favorites or history list.
+    ///
+    /// Returns true if the server was found and removed.
+    pub fn remove_favorite_game(&self, game: &FavoriteGame) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMatchmaking_RemoveFavoriteGame(
+                self.mm,
+                game.app_id.0,
+                game.server_addr.ip().to_bits(),
+                game.server_addr.port(),
+                game.query_port,
+                game.flags.bits(),
+            )
+        }
+    }
+}
+
+bitflags! {
+    /// The lists a favorite game server can be part of
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[repr(C)]
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+    pub struct FavoriteFlags: u32 {
+        const FAVORITE = sys::k_unFavoriteFlagFavorite;
+        const HISTORY  = sys::k_unFavoriteFlagHistory;
+    }
+}
+
+/// A game server in the user's favorites or history list
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FavoriteGame {
+    /// The app the server is running
+    pub app_id: AppId,
+    /// The IP address and connection port of the server
+    pub server_addr: SocketAddrV4,
+    /// The port used to query the server
+    pub query_port: u16,
+    /// The lists the server is part of
+    pub flags: FavoriteFlags,
+    /// The time the user last played on the server, in unix epoch seconds
+    pub last_played_on_server: u32,
 }
 
 /// Filters for the lobbies to be returned from `request_lobby_list`.
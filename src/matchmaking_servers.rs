@@ -171,7 +171,7 @@ macro_rules! gen_server_list_fn {
 }
 
 pub struct GameServerItem {
-    pub appid: u32,
+    pub appid: AppId,
     pub players: i32,
     pub do_not_refresh: bool,
     pub successful_response: bool,
@@ -197,7 +197,7 @@ impl GameServerItem {
     unsafe fn from_ptr(raw: *const sys::gameserveritem_t) -> Self {
         let raw = *raw;
         Self {
-            appid: raw.m_nAppID,
+            appid: AppId(raw.m_nAppID),
             players: raw.m_nPlayers,
             bot_players: raw.m_nBotPlayers,
             ping: Duration::from_millis(raw.m_nPing.try_into().unwrap()),
@@ -0,0 +1,132 @@
+use super::*;
+#[cfg(test)]
+use serial_test::serial;
+
+/// Access to the steam music interface
+///
+/// Allows controlling the music played through the Steam music player.
+pub struct Music {
+    pub(crate) music: *mut sys::ISteamMusic,
+    pub(crate) _inner: Arc<Inner>,
+}
+
+impl Music {
+    /// Checks if Steam music is enabled
+    pub fn is_enabled(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamMusic_BIsEnabled(self.music) }
+    }
+
+    /// Checks if Steam music is currently playing something
+    pub fn is_playing(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamMusic_BIsPlaying(self.music) }
+    }
+
+    /// Returns the current playback status of the Steam music player
+    pub fn playback_status(&self) -> MusicStatus {
+        unsafe { sys::SteamAPI_ISteamMusic_GetPlaybackStatus(self.music).into() }
+    }
+
+    /// Plays the current track
+    pub fn play(&self) {
+        unsafe { sys::SteamAPI_ISteamMusic_Play(self.music) }
+    }
+
+    /// Pauses the current track
+    pub fn pause(&self) {
+        unsafe { sys::SteamAPI_ISteamMusic_Pause(self.music) }
+    }
+
+    /// Plays the previous track in the queue
+    pub fn play_previous(&self) {
+        unsafe { sys::SteamAPI_ISteamMusic_PlayPrevious(self.music) }
+    }
+
+    /// Plays the next track in the queue
+    pub fn play_next(&self) {
+        unsafe { sys::SteamAPI_ISteamMusic_PlayNext(self.music) }
+    }
+
+    /// Sets the volume of the Steam music player, between `0.0` and `1.0`
+    pub fn set_volume(&self, volume: f32) {
+        unsafe { sys::SteamAPI_ISteamMusic_SetVolume(self.music, volume) }
+    }
+
+    /// Returns the volume of the Steam music player, between `0.0` and `1.0`
+    pub fn volume(&self) -> f32 {
+        unsafe { sys::SteamAPI_ISteamMusic_GetVolume(self.music) }
+    }
+}
+
+/// The playback status of the Steam music player
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MusicStatus {
+    Undefined,
+    Playing,
+    Paused,
+    Idle,
+}
+
+impl From<sys::AudioPlayback_Status> for MusicStatus {
+    fn from(status: sys::AudioPlayback_Status) -> MusicStatus {
+        match status {
+            sys::AudioPlayback_Status::AudioPlayback_Playing => MusicStatus::Playing,
+            sys::AudioPlayback_Status::AudioPlayback_Paused => MusicStatus::Paused,
+            sys::AudioPlayback_Status::AudioPlayback_Idle => MusicStatus::Idle,
+            _ => MusicStatus::Undefined,
+        }
+    }
+}
+
+/// Called when the playback status of the Steam music player changes
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlaybackStatusHasChanged {
+    /// The playback status at the time the callback was dispatched
+    pub new_status: MusicStatus,
+}
+
+impl_callback!(_cb: PlaybackStatusHasChanged_t => PlaybackStatusHasChanged {
+    // Steam doesn't include the new status in the callback, so query it instead
+    let music = sys::SteamAPI_SteamMusic_v001();
+    Self {
+        new_status: sys::SteamAPI_ISteamMusic_GetPlaybackStatus(music).into(),
+    }
+});
+
+/// Called when the volume of the Steam music player changes
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeHasChanged {
+    pub new_volume: f32,
+}
+
+impl_callback!(cb: VolumeHasChanged_t => VolumeHasChanged {
+    Self {
+        new_volume: cb.m_flNewVolume,
+    }
+});
+
+#[test]
+#[serial]
+fn test_music() {
+    let client = Client::init().unwrap();
+    let music = client.music();
+
+    let _cb = client.register_callback(|v: PlaybackStatusHasChanged| {
+        println!("Got playback status change: {:?}", v)
+    });
+    let _cb =
+        client.register_callback(|v: VolumeHasChanged| println!("Got volume change: {:?}", v));
+
+    println!("Enabled: {}", music.is_enabled());
+    println!("Playing: {}", music.is_playing());
+    println!("Status: {:?}", music.playback_status());
+    println!("Volume: {}", music.volume());
+    assert!((0.0..=1.0).contains(&music.volume()));
+
+    for _ in 0..20 {
+        client.run_callbacks();
+        ::std::thread::sleep(::std::time::Duration::from_millis(50));
+    }
+}
@@ -0,0 +1,50 @@
+use super::*;
+
+/// Access to the steam music remote interface
+///
+/// Allows a game's music player to be controlled from the Steam music overlay.
+pub struct MusicRemote {
+    pub(crate) music_remote: *mut sys::ISteamMusicRemote,
+    pub(crate) _inner: Arc<Inner>,
+}
+
+impl MusicRemote {
+    /// Declares whether this music remote supports looping playback.
+    pub fn enable_loop_status(&self, value: bool) -> bool {
+        unsafe { sys::SteamAPI_ISteamMusicRemote_EnableLooped(self.music_remote, value) }
+    }
+
+    /// Declares whether this music remote supports shuffled playback.
+    pub fn enable_shuffle_status(&self, value: bool) -> bool {
+        unsafe { sys::SteamAPI_ISteamMusicRemote_EnableShuffled(self.music_remote, value) }
+    }
+
+    /// Tells the Steam music overlay whether looping is currently enabled.
+    ///
+    /// Only has an effect if enabled via [`enable_loop_status`](#method.enable_loop_status).
+    pub fn update_loop_status(&self, loop_enabled: bool) -> bool {
+        unsafe { sys::SteamAPI_ISteamMusicRemote_UpdateLooped(self.music_remote, loop_enabled) }
+    }
+
+    /// Tells the Steam music overlay whether shuffle is currently enabled.
+    ///
+    /// Only has an effect if enabled via [`enable_shuffle_status`](#method.enable_shuffle_status).
+    pub fn update_shuffle_status(&self, shuffle_enabled: bool) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_UpdateShuffled(self.music_remote, shuffle_enabled)
+        }
+    }
+
+    /// Sends the album art of the currently playing entry to the Steam music overlay.
+    ///
+    /// `image_data` must be an encoded PNG or JPEG image.
+    pub fn update_current_entry_cover_art(&self, image_data: &[u8]) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_UpdateCurrentEntryCoverArt(
+                self.music_remote,
+                image_data.as_ptr() as *mut c_void,
+                image_data.len() as u32,
+            )
+        }
+    }
+}
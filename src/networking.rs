@@ -44,6 +44,44 @@ impl Networking {
         unsafe { sys::SteamAPI_ISteamNetworking_CloseP2PSessionWithUser(self.net, user.0) }
     }
 
+    /// Closes the p2p channel with the given user
+    ///
+    /// The session with the user is closed once all of its channels
+    /// have been closed.
+    pub fn close_p2p_channel(&self, user: SteamId, channel: i32) -> bool {
+        unsafe { sys::SteamAPI_ISteamNetworking_CloseP2PChannelWithUser(self.net, user.0, channel) }
+    }
+
+    /// Returns the state of the p2p session with the given user
+    ///
+    /// Returns `None` if there is no session with the user.
+    pub fn p2p_session_state(&self, user: SteamId) -> Option<P2PSessionState> {
+        unsafe {
+            let mut state: sys::P2PSessionState_t = std::mem::zeroed();
+            if sys::SteamAPI_ISteamNetworking_GetP2PSessionState(self.net, user.0, &mut state) {
+                Some(P2PSessionState {
+                    connection_active: state.m_bConnectionActive != 0,
+                    connecting: state.m_bConnecting != 0,
+                    error: state.m_eP2PSessionError,
+                    using_relay: state.m_bUsingRelay != 0,
+                    bytes_queued_for_send: state.m_nBytesQueuedForSend,
+                    packets_queued_for_send: state.m_nPacketsQueuedForSend,
+                })
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Allows or disallows p2p connections to fall back to being
+    /// relayed through the steam servers if a direct connection
+    /// or NAT-traversal cannot be established.
+    ///
+    /// This is allowed by default.
+    pub fn allow_p2p_packet_relay(&self, allow: bool) -> bool {
+        unsafe { sys::SteamAPI_ISteamNetworking_AllowP2PPacketRelay(self.net, allow) }
+    }
+
     /// Sends a packet to the user, starting the
     /// connection if it isn't started already
     pub fn send_p2p_packet(&self, remote: SteamId, send_type: SendType, data: &[u8]) -> bool {
@@ -130,6 +168,24 @@ impl Networking {
     }
 }
 
+/// The state of a p2p session with a user
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct P2PSessionState {
+    /// Whether a connection is currently open
+    pub connection_active: bool,
+    /// Whether the connection is currently being established
+    pub connecting: bool,
+    /// The last error recorded for the session
+    pub error: u8,
+    /// Whether the connection is relayed through the steam servers
+    pub using_relay: bool,
+    /// The number of bytes queued up to be sent
+    pub bytes_queued_for_send: i32,
+    /// The number of packets queued up to be sent
+    pub packets_queued_for_send: i32,
+}
+
 /// Called when a user wants to communicate via p2p
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
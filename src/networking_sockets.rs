@@ -11,7 +11,7 @@ use crate::{CallbackHandle, Inner, SResult};
 #[cfg(test)]
 use serial_test::serial;
 use std::convert::TryInto;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::net::SocketAddr;
 use std::sync::mpsc::Receiver;
 use std::sync::Arc;
@@ -195,6 +195,58 @@ impl NetworkingSockets {
         }
     }
 
+    /// Create a pair of connections that are talking to each other, e.g. a loopback connection.
+    /// This is very useful for testing, or so that your client/server code can work the same
+    /// even when you are running a local "server".
+    ///
+    /// The two connections will immediately be placed into the connected state, and no callbacks
+    /// will be posted immediately.  After this, if you close either connection, the other connection
+    /// will receive a callback, exactly as if they were communicating over the network.  You must
+    /// close *both* sides in order to fully clean up the resources!
+    ///
+    /// By default, internal buffers are used, completely bypassing the network, the chopping up of
+    /// messages into packets, encryption, copying the payload, etc.  This means that loopback
+    /// packets, by default, will not simulate lag or loss.  Passing true for `use_network_loopback`
+    /// will cause the socket pair to send packets through the local network loopback device
+    /// (127.0.0.1) on ephemeral ports.  Fake lag and loss are supported in this case, and CPU time
+    /// is expended to encrypt and decrypt.
+    ///
+    /// If you wish to assign a specific identity to either connection, you may pass a particular
+    /// identity.  Otherwise, if you pass `None`, the respective connection will assume a generic
+    /// "localhost" identity.  If you use real network loopback, this might be translated to the
+    /// actual bound loopback port.  Otherwise, the port will be zero.
+    pub fn create_socket_pair(
+        &self,
+        use_network_loopback: bool,
+        identity1: Option<NetworkingIdentity>,
+        identity2: Option<NetworkingIdentity>,
+    ) -> Result<(NetConnection, NetConnection), InvalidHandle> {
+        let mut handle1 = sys::k_HSteamNetConnection_Invalid;
+        let mut handle2 = sys::k_HSteamNetConnection_Invalid;
+        let was_successful = unsafe {
+            sys::SteamAPI_ISteamNetworkingSockets_CreateSocketPair(
+                self.sockets,
+                &mut handle1,
+                &mut handle2,
+                use_network_loopback,
+                identity1
+                    .as_ref()
+                    .map_or(std::ptr::null(), |identity| identity.as_ptr()),
+                identity2
+                    .as_ref()
+                    .map_or(std::ptr::null(), |identity| identity.as_ptr()),
+            )
+        };
+        if was_successful {
+            Ok((
+                NetConnection::new_independent(handle1, self.sockets, self.inner.clone()),
+                NetConnection::new_independent(handle2, self.sockets, self.inner.clone()),
+            ))
+        } else {
+            Err(InvalidHandle)
+        }
+    }
+
     /// Create a listen socket on the specified virtual port.  The physical UDP port to use
     /// will be determined by the SDR_LISTEN_PORT environment variable.  If a UDP port is not
     /// configured, this call will fail.
@@ -272,6 +324,13 @@ impl NetworkingSockets {
         }
     }
 
+    /// Query our readiness to participate in authenticated communications.
+    ///
+    /// The certificates needed for Steam Datagram Relay connections are obtained
+    /// in the background after [`init_authentication`](#method.init_authentication)
+    /// is called, or when the first authenticated connection is attempted.
+    /// Connections can only be established once this returns
+    /// [`NetworkingAvailability::Current`].
     pub fn get_authentication_status(
         &self,
     ) -> Result<NetworkingAvailability, NetworkingAvailabilityError> {
@@ -351,6 +410,37 @@ impl NetworkingSockets {
             )
         })
     }
+
+    /// Returns very detailed connection stats in diagnostic text format.
+    ///
+    /// Useful for dumping to a log, etc.  The format of this information is
+    /// not documented and may change.
+    pub fn get_detailed_connection_status(
+        &self,
+        connection: &NetConnection,
+    ) -> Result<String, InvalidHandle> {
+        let mut buffer = vec![0; 2048];
+        loop {
+            let result = unsafe {
+                sys::SteamAPI_ISteamNetworkingSockets_GetDetailedConnectionStatus(
+                    self.sockets,
+                    connection.handle,
+                    buffer.as_mut_ptr(),
+                    buffer.len() as _,
+                )
+            };
+            if result < 0 {
+                return Err(InvalidHandle);
+            } else if result > 0 {
+                // The buffer was too small, the result is the size that's needed
+                buffer.resize(result as usize, 0);
+            } else {
+                let status = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+                return Ok(status.to_string_lossy().into_owned());
+            }
+        }
+    }
+
     /// Configure multiple outbound messages streams ("lanes") on a connection, and control head-of-line blocking between them. Messages within a given lane are always sent in the order they are queued, but messages from different lanes may be sent out of order. Each lane has its own message number sequence. The first message sent on each lane will be assigned the number 1.
     ///
     /// Each lane has a "priority". Lower priority lanes will only be processed when all higher-priority lanes are empty. The magnitudes of the priority values are not relevant, only their sort order. Higher numeric values take priority over lower numeric values.
@@ -752,6 +842,10 @@ impl NetConnection {
     }
 
     /// Set a name for the connection, used mostly for debugging
+    ///
+    /// The name is included in the connection's description in diagnostic
+    /// output, so it's worth setting to something recognizable, e.g. the
+    /// player's name.
     pub fn set_connection_name(&self, name: &str) {
         let name = CString::new(name).unwrap();
         unsafe {
@@ -815,9 +909,25 @@ impl NetConnection {
         }
     }
 
-    /// Fetch connection name.  Returns false if handle is invalid
-    pub fn connection_name(&self) -> Result<(), InvalidHandle> {
-        unimplemented!()
+    /// Fetch connection name, as set by [`set_connection_name`](#method.set_connection_name).
+    ///
+    /// Returns `Err(InvalidHandle)` if the handle is invalid.
+    pub fn connection_name(&self) -> Result<String, InvalidHandle> {
+        let mut name = [0; 128];
+        let was_successful = unsafe {
+            sys::SteamAPI_ISteamNetworkingSockets_GetConnectionName(
+                self.sockets,
+                self.handle,
+                name.as_mut_ptr(),
+                name.len() as _,
+            )
+        };
+        if was_successful {
+            let name = unsafe { CStr::from_ptr(name.as_ptr()) };
+            Ok(name.to_string_lossy().into_owned())
+        } else {
+            Err(InvalidHandle)
+        }
     }
 
     /// Flush any messages waiting on the Nagle timer and send them
@@ -1024,6 +1134,34 @@ mod tests {
         assert!(socket_result.is_ok());
     }
 
+    #[test]
+    #[serial]
+    fn test_socket_pair() {
+        let client = Client::init().unwrap();
+        let sockets = client.networking_sockets();
+
+        let (mut first, mut second) = sockets.create_socket_pair(false, None, None).unwrap();
+
+        first.set_connection_name("first");
+        assert_eq!(first.connection_name().unwrap(), "first");
+
+        first
+            .send_message(&[1, 2, 3], SendFlags::RELIABLE_NO_NAGLE)
+            .unwrap();
+        std::thread::sleep(::std::time::Duration::from_millis(50));
+        let messages = second.receive_messages(10).unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].data(), &[1, 2, 3]);
+
+        second
+            .send_message(&[4, 5, 6], SendFlags::RELIABLE_NO_NAGLE)
+            .unwrap();
+        std::thread::sleep(::std::time::Duration::from_millis(50));
+        let messages = first.receive_messages(10).unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].data(), &[4, 5, 6]);
+    }
+
     #[test]
     #[serial]
     fn test_socket_connection() {
@@ -1,7 +1,9 @@
-use crate::networking_types::{NetworkingAvailabilityResult, NetworkingMessage};
+use crate::networking_types::{
+    NetworkingAvailabilityResult, NetworkingConfigEntry, NetworkingMessage,
+};
 use crate::{register_callback, Callback, Inner};
 use std::convert::TryInto;
-use std::ffi::{c_void, CStr};
+use std::ffi::{c_void, CStr, CString};
 use std::sync::Arc;
 
 use steamworks_sys as sys;
@@ -97,6 +99,223 @@ impl NetworkingUtils {
         }
     }
 
+    /// Estimate the round-trip latency between two arbitrary locations, in
+    /// milliseconds.  This is a conservative estimate, based on routing through
+    /// the relay network.  For most basic relayed connections, this ping time
+    /// will be pretty accurate, since it will be based on the route likely to
+    /// be actually used.
+    ///
+    /// Returns `None` if the estimate could not be made, e.g. because the
+    /// locations share no data centers in common.
+    pub fn estimate_ping_time_between_two_locations(
+        &self,
+        location1: &NetworkPingLocation,
+        location2: &NetworkPingLocation,
+    ) -> Option<u32> {
+        unsafe {
+            let ping = sys::SteamAPI_ISteamNetworkingUtils_EstimatePingTimeBetweenTwoLocations(
+                self.utils,
+                &location1.0,
+                &location2.0,
+            );
+            if ping < 0 {
+                None
+            } else {
+                Some(ping as u32)
+            }
+        }
+    }
+
+    /// Same as [`estimate_ping_time_between_two_locations`](#method.estimate_ping_time_between_two_locations),
+    /// but assumes that one location is the local host.  This is a bit faster,
+    /// especially if you need to calculate a bunch of these in a loop to find
+    /// the fastest one.
+    pub fn estimate_ping_time_from_local_host(
+        &self,
+        remote_location: &NetworkPingLocation,
+    ) -> Option<u32> {
+        unsafe {
+            let ping = sys::SteamAPI_ISteamNetworkingUtils_EstimatePingTimeFromLocalHost(
+                self.utils,
+                &remote_location.0,
+            );
+            if ping < 0 {
+                None
+            } else {
+                Some(ping as u32)
+            }
+        }
+    }
+
+    /// Check if the ping data of sufficient recency is available, and if
+    /// it's too old, start refreshing it.
+    ///
+    /// Please only call this function when you *really* do need to force an
+    /// immediate refresh of the data.  (For example, in response to a specific
+    /// user input to refresh this information.)  Don't call it "just in case",
+    /// before every connection, etc.  That will cause extra traffic to be sent
+    /// for no benefit.  The library will automatically refresh the information
+    /// as needed.
+    ///
+    /// Returns true if sufficiently recent data is already available.
+    ///
+    /// Returns false if sufficiently recent data is not available.  In this
+    /// case, ping measurement is initiated, if it is not already active.
+    /// (You cannot restart a measurement already in progress.)
+    pub fn check_ping_data_up_to_date(&self, max_age_seconds: f32) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamNetworkingUtils_CheckPingDataUpToDate(self.utils, max_age_seconds)
+        }
+    }
+
+    /// Return location info for the current host.
+    ///
+    /// Returns the approximate age of the data, in seconds, along with the
+    /// location, or `None` if no data is available.
+    ///
+    /// It takes a few seconds to initialize access to the relay network.  If
+    /// you call this very soon after calling [`init_relay_network_access`](#method.init_relay_network_access),
+    /// the data may not be available yet.
+    ///
+    /// This always return the most up-to-date information we have available
+    /// right now, even if we are in the middle of re-calculating ping times.
+    pub fn local_ping_location(&self) -> Option<(NetworkPingLocation, f32)> {
+        unsafe {
+            let mut location = sys::SteamNetworkPingLocation_t { m_data: [0; 512] };
+            let age =
+                sys::SteamAPI_ISteamNetworkingUtils_GetLocalPingLocation(self.utils, &mut location);
+            if age < 0.0 {
+                None
+            } else {
+                Some((NetworkPingLocation(location), age))
+            }
+        }
+    }
+
+    /// Convert a ping location into a text format suitable for sending over the wire.
+    ///
+    /// The format is a compact and human readable.  However, it is subject to change
+    /// so please do not parse it yourself.
+    pub fn convert_ping_location_to_string(&self, location: &NetworkPingLocation) -> String {
+        unsafe {
+            let mut buffer = vec![0; sys::k_cchMaxSteamNetworkingPingLocationString as usize];
+            sys::SteamAPI_ISteamNetworkingUtils_ConvertPingLocationToString(
+                self.utils,
+                &location.0,
+                buffer.as_mut_ptr(),
+                buffer.len() as _,
+            );
+            CStr::from_ptr(buffer.as_ptr())
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+
+    /// Parse back a string that was generated using
+    /// [`convert_ping_location_to_string`](#method.convert_ping_location_to_string).
+    ///
+    /// Returns `None` if we couldn't understand the string.
+    pub fn parse_ping_location_string(&self, string: &str) -> Option<NetworkPingLocation> {
+        let string = CString::new(string).ok()?;
+        unsafe {
+            let mut location = sys::SteamNetworkPingLocation_t { m_data: [0; 512] };
+            if sys::SteamAPI_ISteamNetworkingUtils_ParsePingLocationString(
+                self.utils,
+                string.as_ptr(),
+                &mut location,
+            ) {
+                Some(NetworkPingLocation(location))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Fetch ping time of best available relayed route from this host to
+    /// the specified data center.
+    ///
+    /// Data centers are identified by their POP ID, the packed representation
+    /// of their three or four character code (e.g. `b"sea"`).
+    ///
+    /// Returns the ping time in milliseconds along with the POP ID of the relay
+    /// the route goes through, or `None` if there is no route or ping data is
+    /// not available yet.
+    pub fn ping_to_data_center(&self, pop_id: u32) -> Option<(u32, u32)> {
+        unsafe {
+            let mut via_relay_pop = 0;
+            let ping = sys::SteamAPI_ISteamNetworkingUtils_GetPingToDataCenter(
+                self.utils,
+                pop_id,
+                &mut via_relay_pop,
+            );
+            if ping < 0 {
+                None
+            } else {
+                Some((ping as u32, via_relay_pop))
+            }
+        }
+    }
+
+    /// Get *direct* ping time to the relays at the point of presence.
+    ///
+    /// Returns `None` if there is no ping data available for this POP yet.
+    pub fn direct_ping_to_pop(&self, pop_id: u32) -> Option<u32> {
+        unsafe {
+            let ping = sys::SteamAPI_ISteamNetworkingUtils_GetDirectPingToPOP(self.utils, pop_id);
+            if ping < 0 {
+                None
+            } else {
+                Some(ping as u32)
+            }
+        }
+    }
+
+    /// Get the list of all known points of presence, usually used together
+    /// with [`direct_ping_to_pop`](#method.direct_ping_to_pop) or
+    /// [`ping_to_data_center`](#method.ping_to_data_center).
+    pub fn pop_list(&self) -> Vec<u32> {
+        unsafe {
+            let count = sys::SteamAPI_ISteamNetworkingUtils_GetPOPCount(self.utils);
+            let mut list = vec![0; count.max(0) as usize];
+            let written = sys::SteamAPI_ISteamNetworkingUtils_GetPOPList(
+                self.utils,
+                list.as_mut_ptr(),
+                list.len() as _,
+            );
+            list.truncate(written.max(0) as usize);
+            list
+        }
+    }
+
+    /// Set a configuration value on the global scope, affecting all
+    /// connections that don't override it.
+    ///
+    /// This can be used to simulate bad network conditions while testing,
+    /// for example:
+    ///
+    /// ```no_run
+    /// # use steamworks::networking_types::{NetworkingConfigEntry, NetworkingConfigValue};
+    /// # let client = steamworks::Client::init().unwrap();
+    /// // Add 100ms of fake latency to all outgoing packets
+    /// client.networking_utils().set_global_config_value(NetworkingConfigEntry::new_int32(
+    ///     NetworkingConfigValue::FakePacketLagSend,
+    ///     100,
+    /// ));
+    /// ```
+    ///
+    /// Returns false if the value could not be set.
+    pub fn set_global_config_value(&self, entry: NetworkingConfigEntry) -> bool {
+        unsafe {
+            let value: sys::SteamNetworkingConfigValue_t = entry.into();
+            sys::SteamAPI_ISteamNetworkingUtils_SetConfigValueStruct(
+                self.utils,
+                &value,
+                sys::ESteamNetworkingConfigScope::k_ESteamNetworkingConfig_Global,
+                0,
+            )
+        }
+    }
+
     /// Register the callback for relay network status updates.
     ///
     /// Calling this more than once replaces the previous callback.
@@ -112,6 +331,15 @@ impl NetworkingUtils {
     }
 }
 
+/// Describes a "location" on the Internet with sufficient detail that we can
+/// reasonably estimate an upper bound on the ping between the two hosts.
+///
+/// This should only be used in the same process!  Do not serialize it, send it
+/// over the wire, or persist it in a file or database!  If you need to do that,
+/// convert it to a string using [`NetworkingUtils::convert_ping_location_to_string`].
+#[derive(Clone, Copy, Debug)]
+pub struct NetworkPingLocation(pub(crate) sys::SteamNetworkPingLocation_t);
+
 #[derive(Debug)]
 pub struct RelayNetworkStatus {
     availability: NetworkingAvailabilityResult,
@@ -0,0 +1,386 @@
+use super::*;
+
+/// Access to the steam parties interface
+///
+/// Parties allow advertising open slots in a game through a beacon, which
+/// other users can respond to in order to join the beacon owner's game.
+pub struct Parties {
+    pub(crate) parties: *mut sys::ISteamParties,
+    pub(crate) inner: Arc<Inner>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PartyBeaconId(pub(crate) u64);
+
+impl PartyBeaconId {
+    /// Creates a `PartyBeaconId` from a raw 64 bit value.
+    ///
+    /// May be useful for deserializing beacon ids from
+    /// a network or save format.
+    pub fn from_raw(id: u64) -> PartyBeaconId {
+        PartyBeaconId(id)
+    }
+
+    /// Returns the raw 64 bit value of the beacon id
+    ///
+    /// May be useful for serializing beacon ids over a
+    /// network or to a save format.
+    pub fn raw(&self) -> u64 {
+        self.0
+    }
+}
+
+/// The kind of place a party beacon is posted to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PartyBeaconLocationType {
+    Invalid,
+    /// A Steam group chat
+    ChatGroup,
+}
+
+impl From<PartyBeaconLocationType> for sys::ESteamPartyBeaconLocationType {
+    fn from(ty: PartyBeaconLocationType) -> sys::ESteamPartyBeaconLocationType {
+        match ty {
+            PartyBeaconLocationType::Invalid => {
+                sys::ESteamPartyBeaconLocationType::k_ESteamPartyBeaconLocationType_Invalid
+            }
+            PartyBeaconLocationType::ChatGroup => {
+                sys::ESteamPartyBeaconLocationType::k_ESteamPartyBeaconLocationType_ChatGroup
+            }
+        }
+    }
+}
+
+impl From<sys::ESteamPartyBeaconLocationType> for PartyBeaconLocationType {
+    fn from(ty: sys::ESteamPartyBeaconLocationType) -> PartyBeaconLocationType {
+        match ty {
+            sys::ESteamPartyBeaconLocationType::k_ESteamPartyBeaconLocationType_ChatGroup => {
+                PartyBeaconLocationType::ChatGroup
+            }
+            _ => PartyBeaconLocationType::Invalid,
+        }
+    }
+}
+
+/// A place a party beacon can be posted to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PartyBeaconLocation {
+    pub location_type: PartyBeaconLocationType,
+    pub location_id: u64,
+}
+
+impl From<PartyBeaconLocation> for sys::SteamPartyBeaconLocation_t {
+    fn from(location: PartyBeaconLocation) -> sys::SteamPartyBeaconLocation_t {
+        sys::SteamPartyBeaconLocation_t {
+            m_eType: location.location_type.into(),
+            m_ulLocationID: location.location_id,
+        }
+    }
+}
+
+impl From<sys::SteamPartyBeaconLocation_t> for PartyBeaconLocation {
+    fn from(location: sys::SteamPartyBeaconLocation_t) -> PartyBeaconLocation {
+        PartyBeaconLocation {
+            location_type: location.m_eType.into(),
+            location_id: location.m_ulLocationID,
+        }
+    }
+}
+
+impl Parties {
+    /// Returns the number of beacons the current user has active
+    pub fn get_num_active_beacons(&self) -> u32 {
+        unsafe { sys::SteamAPI_ISteamParties_GetNumActiveBeacons(self.parties) }
+    }
+
+    /// Returns the id of the active beacon at `index`, between 0 and
+    /// [`get_num_active_beacons()`](#method.get_num_active_beacons).
+    ///
+    /// Returns `None` if the index is out of range.
+    pub fn get_beacon_by_index(&self, index: u32) -> Option<PartyBeaconId> {
+        unsafe {
+            let beacon = sys::SteamAPI_ISteamParties_GetBeaconByIndex(self.parties, index);
+            if beacon == sys::k_ulPartyBeaconIdInvalid {
+                None
+            } else {
+                Some(PartyBeaconId(beacon))
+            }
+        }
+    }
+
+    /// Returns the owner, location and metadata of a beacon.
+    ///
+    /// Returns `None` if the beacon doesn't exist.
+    pub fn get_beacon_details(&self, beacon: PartyBeaconId) -> Option<BeaconDetails> {
+        unsafe {
+            let mut owner: sys::CSteamID = SteamId(0).into();
+            let mut location = sys::SteamPartyBeaconLocation_t {
+                m_eType:
+                    sys::ESteamPartyBeaconLocationType::k_ESteamPartyBeaconLocationType_Invalid,
+                m_ulLocationID: 0,
+            };
+            let mut metadata = [0 as c_char; 8192];
+            if sys::SteamAPI_ISteamParties_GetBeaconDetails(
+                self.parties,
+                beacon.0,
+                &mut owner,
+                &mut location,
+                metadata.as_mut_ptr(),
+                metadata.len() as _,
+            ) {
+                Some(BeaconDetails {
+                    owner: owner.into(),
+                    location: location.into(),
+                    metadata: CStr::from_ptr(metadata.as_ptr())
+                        .to_string_lossy()
+                        .into_owned(),
+                })
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the locations the current user can create beacons at.
+    pub fn get_available_beacon_locations(&self) -> Vec<PartyBeaconLocation> {
+        unsafe {
+            let mut count = 0;
+            if !sys::SteamAPI_ISteamParties_GetNumAvailableBeaconLocations(self.parties, &mut count)
+                || count == 0
+            {
+                return Vec::new();
+            }
+            let mut locations = vec![
+                sys::SteamPartyBeaconLocation_t {
+                    m_eType:
+                        sys::ESteamPartyBeaconLocationType::k_ESteamPartyBeaconLocationType_Invalid,
+                    m_ulLocationID: 0,
+                };
+                count as usize
+            ];
+            if !sys::SteamAPI_ISteamParties_GetAvailableBeaconLocations(
+                self.parties,
+                locations.as_mut_ptr(),
+                count,
+            ) {
+                return Vec::new();
+            }
+            locations.into_iter().map(|v| v.into()).collect()
+        }
+    }
+
+    /// Returns the display name of a beacon location, e.g. the name of the
+    /// group chat.
+    pub fn get_beacon_location_name(&self, location: &PartyBeaconLocation) -> Option<String> {
+        self.get_beacon_location_data(
+            location,
+            sys::ESteamPartyBeaconLocationData::k_ESteamPartyBeaconLocationDataName,
+        )
+    }
+
+    /// Returns the url of the medium sized icon of a beacon location.
+    pub fn get_beacon_location_url(&self, location: &PartyBeaconLocation) -> Option<String> {
+        self.get_beacon_location_data(
+            location,
+            sys::ESteamPartyBeaconLocationData::k_ESteamPartyBeaconLocationDataIconURLMedium,
+        )
+    }
+
+    fn get_beacon_location_data(
+        &self,
+        location: &PartyBeaconLocation,
+        data: sys::ESteamPartyBeaconLocationData,
+    ) -> Option<String> {
+        unsafe {
+            let mut buffer = [0 as c_char; 1024];
+            if sys::SteamAPI_ISteamParties_GetBeaconLocationData(
+                self.parties,
+                (*location).into(),
+                data,
+                buffer.as_mut_ptr(),
+                buffer.len() as _,
+            ) {
+                Some(
+                    CStr::from_ptr(buffer.as_ptr())
+                        .to_string_lossy()
+                        .into_owned(),
+                )
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Creates a beacon advertising `open_slots` slots at the given location.
+    ///
+    /// Users that join through the beacon receive `connect_string` so they can
+    /// connect to the game. `metadata` is displayed alongside the beacon.
+    ///
+    /// Once a user has connected, call
+    /// [`on_reservation_completed()`](#method.on_reservation_completed) to
+    /// consume the slot they reserved.
+    pub fn create_beacon<F>(
+        &self,
+        open_slots: u32,
+        location: &PartyBeaconLocation,
+        connect_string: &str,
+        metadata: &str,
+        cb: F,
+    ) where
+        F: FnOnce(SResult<PartyBeaconId>) + 'static + Send,
+    {
+        let connect_string = CString::new(connect_string).unwrap();
+        let metadata = CString::new(metadata).unwrap();
+        let mut location: sys::SteamPartyBeaconLocation_t = (*location).into();
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamParties_CreateBeacon(
+                self.parties,
+                open_slots,
+                &mut location,
+                connect_string.as_ptr(),
+                metadata.as_ptr(),
+            );
+            register_call_result::<sys::CreateBeaconCallback_t, _>(
+                &self.inner,
+                api_call,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else {
+                        crate::to_steam_result(v.m_eResult).map(|_| PartyBeaconId(v.m_ulBeaconID))
+                    })
+                },
+            );
+        }
+    }
+
+    /// Joins the party advertised by the beacon, reserving one of its slots.
+    ///
+    /// On success the callback receives the steam id of the beacon owner and
+    /// the connect string to use to join their game.
+    pub fn join_party<F>(&self, beacon: PartyBeaconId, cb: F)
+    where
+        F: FnOnce(SResult<JoinParty>) + 'static + Send,
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamParties_JoinParty(self.parties, beacon.0);
+            register_call_result::<sys::JoinPartyCallback_t, _>(
+                &self.inner,
+                api_call,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else {
+                        crate::to_steam_result(v.m_eResult).map(|_| JoinParty {
+                            beacon: PartyBeaconId(v.m_ulBeaconID),
+                            owner: v.m_SteamIDBeaconOwner.into(),
+                            connect_string: CStr::from_ptr(v.m_rgchConnectString.as_ptr())
+                                .to_string_lossy()
+                                .into_owned(),
+                        })
+                    })
+                },
+            );
+        }
+    }
+
+    /// Releases the slot reserved by `user` through the beacon.
+    ///
+    /// Should be called if the user never connects to the game, e.g. after a
+    /// timeout.
+    pub fn cancel_reservation(&self, beacon: PartyBeaconId, user: SteamId) {
+        unsafe {
+            sys::SteamAPI_ISteamParties_CancelReservation(self.parties, beacon.0, user.0);
+        }
+    }
+
+    /// Closes the beacon so no more users can join through it.
+    pub fn destroy_beacon(&self, beacon: PartyBeaconId) -> bool {
+        unsafe { sys::SteamAPI_ISteamParties_DestroyBeacon(self.parties, beacon.0) }
+    }
+
+    /// Changes the number of slots advertised by the beacon.
+    ///
+    /// Useful when users join the game through other means than the beacon.
+    pub fn change_num_open_slots<F>(&self, beacon: PartyBeaconId, open_slots: u32, cb: F)
+    where
+        F: FnOnce(SResult<()>) + 'static + Send,
+    {
+        unsafe {
+            let api_call =
+                sys::SteamAPI_ISteamParties_ChangeNumOpenSlots(self.parties, beacon.0, open_slots);
+            register_call_result::<sys::ChangeNumOpenSlotsCallback_t, _>(
+                &self.inner,
+                api_call,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else {
+                        crate::to_steam_result(v.m_eResult)
+                    })
+                },
+            );
+        }
+    }
+
+    /// Marks the slot reserved by `user` through the beacon as used.
+    ///
+    /// Should be called once the user has successfully connected to the game.
+    pub fn on_reservation_completed(&self, beacon: PartyBeaconId, user: SteamId) {
+        unsafe {
+            sys::SteamAPI_ISteamParties_OnReservationCompleted(self.parties, beacon.0, user.0);
+        }
+    }
+}
+
+/// The result of joining a party through [`Parties::join_party`]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JoinParty {
+    pub beacon: PartyBeaconId,
+    /// The user that created the beacon
+    pub owner: SteamId,
+    /// The string to use to connect to the beacon owner's game
+    pub connect_string: String,
+}
+
+/// Details about a beacon, see [`Parties::get_beacon_details`]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BeaconDetails {
+    /// The user that created the beacon
+    pub owner: SteamId,
+    pub location: PartyBeaconLocation,
+    pub metadata: String,
+}
+
+/// Called when the list of beacons owned by the current user changes.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ActiveBeaconsUpdated;
+
+impl_callback!(_cb: ActiveBeaconsUpdated_t => ActiveBeaconsUpdated {
+    Self
+});
+
+/// Called on the beacon owner's side when a user has joined through one of
+/// their beacons.
+///
+/// The owner should call [`Parties::on_reservation_completed`] once the user
+/// has connected, or [`Parties::cancel_reservation`] if they never do.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReservationNotificationCallback {
+    pub beacon_id: PartyBeaconId,
+    pub steam_id_joiner: SteamId,
+}
+
+impl_callback!(cb: ReservationNotificationCallback_t => ReservationNotificationCallback {
+    Self {
+        beacon_id: PartyBeaconId(cb.m_ulBeaconID),
+        steam_id_joiner: cb.m_steamIDJoiner.into(),
+    }
+});
@@ -0,0 +1,16 @@
+//! Re-exports of the most commonly used types.
+//!
+//! ```no_run
+//! use steamworks::prelude::*;
+//! ```
+
+pub use crate::networking_messages::NetworkingMessages;
+pub use crate::networking_sockets::NetworkingSockets;
+pub use crate::networking_utils::NetworkingUtils;
+pub use crate::screenshots::Screenshots;
+pub use crate::timeline::Timeline;
+pub use crate::{
+    AccountId, AppId, Apps, CallResult, Callback, CallbackHandle, CallbackResult, Client, Friends,
+    GameId, Input, Matchmaking, MatchmakingServers, Networking, RemotePlay, RemoteStorage,
+    SIResult, SResult, Server, SteamError, SteamId, User, UserStats, Utils, UGC,
+};
@@ -1,5 +1,6 @@
 use super::*;
 
+/// Access to the steam remote play interface
 pub struct RemotePlay {
     pub(crate) rp: *mut sys::ISteamRemotePlay,
     pub(crate) inner: Arc<Inner>,
@@ -36,6 +37,16 @@ impl RemotePlay {
         }
     }
 
+    /// Invites a friend to join the game using Remote Play Together
+    ///
+    /// Unlike [`RemotePlaySession::invite`] this doesn't require an active
+    /// session. Returns `false` if the invite couldn't be sent.
+    pub fn send_remote_play_together_invite(&self, friend: SteamId) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamRemotePlay_BSendRemotePlayTogetherInvite(self.rp, friend.raw())
+        }
+    }
+
     /// Get a remote play session from a session ID. The session may or may not be valid or active
     pub fn session(&self, session: RemotePlaySessionId) -> RemotePlaySession {
         RemotePlaySession {
@@ -74,7 +85,12 @@ pub struct RemotePlaySession {
     pub(crate) _inner: Arc<Inner>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The kind of device a Remote Play client is running on
+///
+/// Unknown form factors are reported as `None` by
+/// [`RemotePlaySession::client_form_factor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SteamDeviceFormFactor {
     Phone,
     Tablet,
@@ -82,6 +98,18 @@ pub enum SteamDeviceFormFactor {
     TV,
 }
 
+impl fmt::Display for SteamDeviceFormFactor {
+    /// Formats the form factor as a human-readable name, e.g. for session info UI
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SteamDeviceFormFactor::Phone => "Phone",
+            SteamDeviceFormFactor::Tablet => "Tablet",
+            SteamDeviceFormFactor::Computer => "Computer",
+            SteamDeviceFormFactor::TV => "TV",
+        })
+    }
+}
+
 impl RemotePlaySession {
     /// Get the user associated with this Remote Play session. This is either the logged in user or a friend when Remote
     /// Playing Together.
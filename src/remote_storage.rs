@@ -10,6 +10,7 @@ pub struct RemoteStorage {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PublishedFileVisibility {
     Public,
     FriendsOnly,
@@ -76,6 +77,22 @@ impl RemoteStorage {
         unsafe { sys::SteamAPI_ISteamRemoteStorage_IsCloudEnabledForAccount(self.rs) }
     }
 
+    /// Returns the total and the remaining amount of bytes the current
+    /// user can store in the steam cloud for the application
+    ///
+    /// Returns `None` if the quota couldn't be retrieved.
+    pub fn quota(&self) -> Option<(u64, u64)> {
+        unsafe {
+            let mut total = 0;
+            let mut available = 0;
+            if sys::SteamAPI_ISteamRemoteStorage_GetQuota(self.rs, &mut total, &mut available) {
+                Some((total, available))
+            } else {
+                None
+            }
+        }
+    }
+
     /// Returns information about all files in the cloud storage
     pub fn files(&self) -> Vec<SteamFileInfo> {
         unsafe {
@@ -191,6 +208,10 @@ impl SteamFile {
         RemoteStoragePlatforms::from_bits_truncate(bits.0 as _)
     }
 
+    /// Opens the file for writing, replacing its previous contents
+    ///
+    /// The data is committed to the steam cloud once the returned
+    /// writer is dropped.
     pub fn write(self) -> SteamFileWriter {
         unsafe {
             let handle =
@@ -199,6 +220,7 @@ impl SteamFile {
         }
     }
 
+    /// Opens the file for reading
     pub fn read(self) -> SteamFileReader {
         unsafe {
             SteamFileReader {
@@ -210,6 +232,82 @@ impl SteamFile {
         }
     }
 
+    /// Writes `data` to the file without blocking, replacing its previous contents
+    ///
+    /// The data is copied before this returns, the callback is called
+    /// once the write has completed.
+    pub fn write_async(
+        &self,
+        data: &[u8],
+        cb: impl FnOnce(Result<(), SteamError>) + 'static + Send,
+    ) {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamRemoteStorage_FileWriteAsync(
+                self.rs,
+                self.name.as_ptr(),
+                data.as_ptr().cast(),
+                data.len() as _,
+            );
+            register_call_result::<sys::RemoteStorageFileWriteAsyncComplete_t, _>(
+                &self._inner,
+                api_call,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else {
+                        crate::to_steam_result(v.m_eResult)
+                    })
+                },
+            );
+        }
+    }
+
+    /// Reads `len` bytes starting at `offset` from the file without blocking
+    ///
+    /// The callback receives the data that was read once the read has completed.
+    pub fn read_async(
+        &self,
+        offset: u32,
+        len: u32,
+        cb: impl FnOnce(Result<Vec<u8>, SteamError>) + 'static + Send,
+    ) {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamRemoteStorage_FileReadAsync(
+                self.rs,
+                self.name.as_ptr(),
+                offset,
+                len,
+            );
+            let rs = self.rs as isize;
+            register_call_result::<sys::RemoteStorageFileReadAsyncComplete_t, _>(
+                &self._inner,
+                api_call,
+                move |v, io_error| {
+                    if io_error {
+                        cb(Err(SteamError::IOFailure));
+                        return;
+                    }
+                    if v.m_eResult != sys::EResult::k_EResultOK {
+                        cb(Err(v.m_eResult.into()));
+                        return;
+                    }
+
+                    let mut data = vec![0u8; v.m_cubRead as usize];
+                    if sys::SteamAPI_ISteamRemoteStorage_FileReadAsyncComplete(
+                        rs as *mut _,
+                        v.m_hFileReadAsync,
+                        data.as_mut_ptr().cast(),
+                        v.m_cubRead,
+                    ) {
+                        cb(Ok(data))
+                    } else {
+                        cb(Err(SteamError::IOFailure))
+                    }
+                },
+            );
+        }
+    }
+
     pub fn share(&self, cb: impl FnOnce(Result<u64, SteamError>) + 'static + Send) {
         let api_call =
             unsafe { sys::SteamAPI_ISteamRemoteStorage_FileShare(self.rs, self.name.as_ptr()) };
@@ -210,6 +210,25 @@ impl Server {
         unsafe { SteamId(sys::SteamAPI_ISteamGameServer_GetSteamID(self.server)) }
     }
 
+    /// Returns the public IP of the server as seen by Steam.
+    ///
+    /// Returns `None` if the server isn't logged on yet or the address
+    /// isn't an IPv4 address.
+    pub fn get_public_ip(&self) -> Option<Ipv4Addr> {
+        unsafe {
+            let ip = sys::SteamAPI_ISteamGameServer_GetPublicIP(self.server);
+            // Copy out of the packed struct before comparing
+            let ip_type = ip.m_eType;
+            if ip_type != sys::ESteamIPType::k_ESteamIPTypeIPv4 {
+                return None;
+            }
+            match ip.__bindgen_anon_1.m_unIPv4 {
+                0 => None,
+                ip => Some(Ipv4Addr::from(ip)),
+            }
+        }
+    }
+
     /// Retrieve an authentication session ticket that can be sent
     /// to an entity that wishes to verify you.
     ///
@@ -310,6 +329,49 @@ impl Server {
         }
     }
 
+    /// Handles receiving a new connection from a client using the older
+    /// server-side authentication flow.
+    ///
+    /// This is distinct from [`begin_authentication_session`](Self::begin_authentication_session),
+    /// which Valve recommends for new games. The `ticket` has to be created on the client
+    /// with the legacy `InitiateGameConnection` call, and the result of the
+    /// authentication is posted as a `ValidateAuthTicketResponse` callback.
+    ///
+    /// Returns the steam id of the connecting user, or `None` if the ticket was
+    /// rejected outright. When the user leaves the server you must call
+    /// [`send_user_disconnect`](Self::send_user_disconnect).
+    pub fn send_user_connect_and_authenticate(
+        &self,
+        ip: Ipv4Addr,
+        ticket: &[u8],
+    ) -> Option<SteamId> {
+        unsafe {
+            let mut user = sys::CSteamID {
+                m_steamid: sys::CSteamID_SteamID_t { m_unAll64Bits: 0 },
+            };
+            if sys::SteamAPI_ISteamGameServer_SendUserConnectAndAuthenticate_DEPRECATED(
+                self.server,
+                ip.to_bits(),
+                ticket.as_ptr().cast(),
+                ticket.len() as _,
+                &mut user,
+            ) {
+                Some(user.into())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Notifies steam that a user authenticated with
+    /// [`send_user_connect_and_authenticate`](Self::send_user_connect_and_authenticate)
+    /// has left the server.
+    pub fn send_user_disconnect(&self, user: SteamId) {
+        unsafe {
+            sys::SteamAPI_ISteamGameServer_SendUserDisconnect_DEPRECATED(self.server, user.0);
+        }
+    }
+
     /// Server browser related query packet processing for shared socket mode.  These are used
     /// when you pass STEAMGAMESERVER_QUERY_PORT_SHARED as the query port to SteamGameServer_Init.
     /// IP address and port are in host order, i.e 127.0.0.1 == 0x7f000001
@@ -422,6 +484,28 @@ impl Server {
         }
     }
 
+    /// Begin process of logging the game server out of steam
+    ///
+    /// A [`SteamServersDisconnected`] callback is posted once the server is logged off.
+    pub fn log_off(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamGameServer_LogOff(self.server);
+        }
+    }
+
+    /// Returns whether the server is currently connected to steam and logged on
+    pub fn is_logged_on(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamGameServer_BLoggedOn(self.server) }
+    }
+
+    /// Returns whether the server requires VAC secure connections, as set
+    /// by the server mode it was started with
+    ///
+    /// Only valid once [`SteamServersConnected`] has been posted.
+    pub fn is_secure(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamGameServer_BSecure(self.server) }
+    }
+
     /// If active, updates the master server with this server's presence so players can find it via
     /// the steam matchmaking/server browser interfaces.
     pub fn enable_heartbeats(&self, active: bool) {
@@ -542,6 +626,18 @@ impl Server {
         }
     }
 
+    /// Returns an accessor to the steam game server stats interface
+    pub fn stats(&self) -> GameServerStats {
+        unsafe {
+            let server_stats = sys::SteamAPI_SteamGameServerStats_v001();
+            debug_assert!(!server_stats.is_null());
+            GameServerStats {
+                server_stats,
+                inner: self.inner.clone(),
+            }
+        }
+    }
+
     /// Returns an accessor to the steam utils interface
     pub fn utils(&self) -> Utils {
         unsafe {
@@ -0,0 +1,222 @@
+use super::*;
+
+/// Access to the steam game server stats interface
+///
+/// Lets a game server read and write the stats and achievements of the
+/// users connected to it.
+pub struct GameServerStats {
+    pub(crate) server_stats: *mut sys::ISteamGameServerStats,
+    pub(crate) inner: Arc<Inner>,
+}
+
+impl GameServerStats {
+    /// Asynchronously downloads the stats and achievements of a user.
+    ///
+    /// The callback receives the id of the user once the stats are available.
+    /// This has to complete successfully before the stats of the user can be
+    /// read or written.
+    pub fn request_user_stats<F>(&self, user: SteamId, cb: F)
+    where
+        F: FnOnce(SResult<SteamId>) + 'static + Send,
+    {
+        unsafe {
+            let api_call =
+                sys::SteamAPI_ISteamGameServerStats_RequestUserStats(self.server_stats, user.0);
+            register_call_result::<sys::GSStatsReceived_t, _>(
+                &self.inner,
+                api_call,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else {
+                        crate::to_steam_result(v.m_eResult).map(|_| v.m_steamIDUser.into())
+                    })
+                },
+            );
+        }
+    }
+
+    /// Gets the value of a given stat for the specified user
+    ///
+    /// The specified stat must exist and match the type set on the Steamworks App Admin website.
+    ///
+    /// Requires [`request_user_stats()`](#method.request_user_stats) to have completed successfully.
+    pub fn get_user_stat_i32(&self, user: SteamId, name: &str) -> Result<i32, ()> {
+        let name = CString::new(name).unwrap();
+
+        let mut value: i32 = 0;
+        let success = unsafe {
+            sys::SteamAPI_ISteamGameServerStats_GetUserStatInt32(
+                self.server_stats,
+                user.0,
+                name.as_ptr(),
+                &mut value,
+            )
+        };
+        if success {
+            Ok(value)
+        } else {
+            Err(())
+        }
+    }
+
+    /// Gets the value of a given stat for the specified user
+    ///
+    /// The specified stat must exist and match the type set on the Steamworks App Admin website.
+    ///
+    /// Requires [`request_user_stats()`](#method.request_user_stats) to have completed successfully.
+    pub fn get_user_stat_f32(&self, user: SteamId, name: &str) -> Result<f32, ()> {
+        let name = CString::new(name).unwrap();
+
+        let mut value: f32 = 0.0;
+        let success = unsafe {
+            sys::SteamAPI_ISteamGameServerStats_GetUserStatFloat(
+                self.server_stats,
+                user.0,
+                name.as_ptr(),
+                &mut value,
+            )
+        };
+        if success {
+            Ok(value)
+        } else {
+            Err(())
+        }
+    }
+
+    /// Sets / updates the value of a given stat for the specified user
+    ///
+    /// This call only changes the value in-memory. To commit the stats you
+    /// must call [`store_user_stats()`](#method.store_user_stats)
+    ///
+    /// Only stats that are marked as settable by game servers on the
+    /// Steamworks App Admin website can be set.
+    pub fn set_user_stat_i32(&self, user: SteamId, name: &str, stat: i32) -> Result<(), ()> {
+        let name = CString::new(name).unwrap();
+
+        let success = unsafe {
+            sys::SteamAPI_ISteamGameServerStats_SetUserStatInt32(
+                self.server_stats,
+                user.0,
+                name.as_ptr(),
+                stat,
+            )
+        };
+        if success {
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
+    /// Sets / updates the value of a given stat for the specified user
+    ///
+    /// This call only changes the value in-memory. To commit the stats you
+    /// must call [`store_user_stats()`](#method.store_user_stats)
+    ///
+    /// Only stats that are marked as settable by game servers on the
+    /// Steamworks App Admin website can be set.
+    pub fn set_user_stat_f32(&self, user: SteamId, name: &str, stat: f32) -> Result<(), ()> {
+        let name = CString::new(name).unwrap();
+
+        let success = unsafe {
+            sys::SteamAPI_ISteamGameServerStats_SetUserStatFloat(
+                self.server_stats,
+                user.0,
+                name.as_ptr(),
+                stat,
+            )
+        };
+        if success {
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
+    /// Gets whether the specified user has unlocked the given achievement
+    ///
+    /// Requires [`request_user_stats()`](#method.request_user_stats) to have completed successfully.
+    pub fn get_user_achievement(&self, user: SteamId, name: &str) -> Result<bool, ()> {
+        let name = CString::new(name).unwrap();
+
+        let mut achieved = false;
+        let success = unsafe {
+            sys::SteamAPI_ISteamGameServerStats_GetUserAchievement(
+                self.server_stats,
+                user.0,
+                name.as_ptr(),
+                &mut achieved,
+            )
+        };
+        if success {
+            Ok(achieved)
+        } else {
+            Err(())
+        }
+    }
+
+    /// Unlocks the given achievement for the specified user
+    ///
+    /// To commit the change you must call [`store_user_stats()`](#method.store_user_stats)
+    pub fn set_user_achievement(&self, user: SteamId, name: &str) -> Result<(), ()> {
+        let name = CString::new(name).unwrap();
+
+        let success = unsafe {
+            sys::SteamAPI_ISteamGameServerStats_SetUserAchievement(
+                self.server_stats,
+                user.0,
+                name.as_ptr(),
+            )
+        };
+        if success {
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
+    /// Resets the unlock status of the given achievement for the specified user
+    ///
+    /// To commit the change you must call [`store_user_stats()`](#method.store_user_stats)
+    pub fn clear_user_achievement(&self, user: SteamId, name: &str) -> Result<(), ()> {
+        let name = CString::new(name).unwrap();
+
+        let success = unsafe {
+            sys::SteamAPI_ISteamGameServerStats_ClearUserAchievement(
+                self.server_stats,
+                user.0,
+                name.as_ptr(),
+            )
+        };
+        if success {
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
+    /// Asynchronously uploads the changed stats and achievements of a user.
+    ///
+    /// The callback receives the id of the user once the stats are stored.
+    pub fn store_user_stats<F>(&self, user: SteamId, cb: F)
+    where
+        F: FnOnce(SResult<SteamId>) + 'static + Send,
+    {
+        unsafe {
+            let api_call =
+                sys::SteamAPI_ISteamGameServerStats_StoreUserStats(self.server_stats, user.0);
+            register_call_result::<sys::GSStatsStored_t, _>(
+                &self.inner,
+                api_call,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else {
+                        crate::to_steam_result(v.m_eResult).map(|_| v.m_steamIDUser.into())
+                    })
+                },
+            );
+        }
+    }
+}
@@ -8,14 +8,24 @@ pub struct Timeline {
     pub(crate) _inner: Arc<Inner>,
 }
 
+/// The mode the game is in, used by [`Timeline::set_timeline_game_mode`]
+/// to pick the color of the timeline bar.
 pub enum TimelineGameMode {
     /// The player is fully loaded into the game and playing.
+    ///
+    /// Shown as the default, most prominent color of the timeline bar.
     Playing,
     /// The player is in a multiplayer lobby.
+    ///
+    /// Shown in a dimmed color.
     Staging,
     /// The player is in the game's main menu or a pause menu.
+    ///
+    /// Shown in a dimmed color.
     Menus,
     /// The player is waiting for a loading screen.
+    ///
+    /// Shown in a dimmed color.
     LoadingScreen,
 }
 
@@ -58,8 +68,16 @@ impl From<TimelineEventClipPriority> for sys::ETimelineEventClipPriority {
     }
 }
 
+/// A handle to an event added to the timeline
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimelineEventHandle(pub(crate) sys::TimelineEventHandle_t);
+
 impl Timeline {
     /// Changes the color of the timeline bar.
+    ///
+    /// The game should call this whenever it changes between the modes of
+    /// [`TimelineGameMode`], so that the user can tell gameplay apart from
+    /// menus and loading screens when looking for moments to clip.
     pub fn set_timeline_game_mode(&self, mode: TimelineGameMode) {
         if self.disabled {
             return;
@@ -90,6 +108,9 @@ impl Timeline {
     }
 
     /// Clears the previous set game state in the timeline.
+    ///
+    /// `duration` is the time offset from now at which the description is
+    /// removed, pass [`Duration::ZERO`] to remove it immediately.
     pub fn clear_timeline_state_description(&self, duration: Duration) {
         if self.disabled {
             return;
@@ -103,6 +124,8 @@ impl Timeline {
     /// Use this to mark an event on the Timeline.
     /// The event can be instantaneous or take some amount of time to complete,
     /// depending on the value passed in `duration`.
+    ///
+    /// Returns `None` if the timeline isn't supported by the running Steam client.
     pub fn add_timeline_event(
         &self,
         icon: &str,
@@ -112,9 +135,9 @@ impl Timeline {
         start_offset_seconds: f32,
         duration: Duration,
         clip_priority: TimelineEventClipPriority,
-    ) {
+    ) -> Option<TimelineEventHandle> {
         if self.disabled {
-            return;
+            return None;
         }
 
         let icon = CString::new(icon).unwrap();
@@ -123,16 +146,134 @@ impl Timeline {
         let duration = duration.as_secs_f32();
 
         unsafe {
-            let _handle = sys::SteamAPI_ISteamTimeline_AddRangeTimelineEvent(
+            let handle = sys::SteamAPI_ISteamTimeline_AddRangeTimelineEvent(
                 self.timeline,
-                icon.as_ptr(),
                 title.as_ptr(),
                 description.as_ptr(),
+                icon.as_ptr(),
                 priority,
                 start_offset_seconds,
                 duration,
                 clip_priority.into(),
             );
+            Some(TimelineEventHandle(handle))
+        }
+    }
+
+    /// Removes an event added with [`add_timeline_event`](Self::add_timeline_event)
+    /// or [`start_range_timeline_event`](Self::start_range_timeline_event) from the timeline.
+    pub fn remove_timeline_event(&self, event: TimelineEventHandle) {
+        if self.disabled {
+            return;
+        }
+
+        unsafe { sys::SteamAPI_ISteamTimeline_RemoveTimelineEvent(self.timeline, event.0) }
+    }
+
+    /// Opens the Steam overlay to the recording of the game phase with the given id.
+    pub fn open_overlay_to_game_phase(&self, phase_id: &str) {
+        if self.disabled {
+            return;
+        }
+
+        let phase_id = CString::new(phase_id).unwrap();
+
+        unsafe {
+            sys::SteamAPI_ISteamTimeline_OpenOverlayToGamePhase(self.timeline, phase_id.as_ptr())
+        }
+    }
+
+    /// Starts an event on the timeline that lasts until it is ended with
+    /// [`end_range_timeline_event`](Self::end_range_timeline_event).
+    ///
+    /// Use this for events with an unknown duration, e.g. a boss fight.
+    /// `start_offset_seconds` is the offset from now at which the event starts.
+    ///
+    /// Returns `None` if the timeline isn't supported by the running Steam client.
+    pub fn start_range_timeline_event(
+        &self,
+        icon: &str,
+        title: &str,
+        description: &str,
+        priority: u32,
+        start_offset_seconds: f32,
+        clip_priority: TimelineEventClipPriority,
+    ) -> Option<TimelineEventHandle> {
+        if self.disabled {
+            return None;
+        }
+
+        let icon = CString::new(icon).unwrap();
+        let title = CString::new(title).unwrap();
+        let description = CString::new(description).unwrap();
+
+        unsafe {
+            let handle = sys::SteamAPI_ISteamTimeline_StartRangeTimelineEvent(
+                self.timeline,
+                title.as_ptr(),
+                description.as_ptr(),
+                icon.as_ptr(),
+                priority,
+                start_offset_seconds,
+                clip_priority.into(),
+            );
+            Some(TimelineEventHandle(handle))
+        }
+    }
+
+    /// Updates the details of an event started with
+    /// [`start_range_timeline_event`](Self::start_range_timeline_event).
+    ///
+    /// Details passed as `None` are sent to Steam as null and are not changed.
+    pub fn update_range_timeline_event(
+        &self,
+        event: TimelineEventHandle,
+        icon: Option<&str>,
+        title: Option<&str>,
+        description: Option<&str>,
+        priority: u32,
+        clip_priority: TimelineEventClipPriority,
+    ) {
+        if self.disabled {
+            return;
+        }
+
+        let icon = icon.map(|icon| CString::new(icon).unwrap());
+        let title = title.map(|title| CString::new(title).unwrap());
+        let description = description.map(|description| CString::new(description).unwrap());
+
+        unsafe {
+            sys::SteamAPI_ISteamTimeline_UpdateRangeTimelineEvent(
+                self.timeline,
+                event.0,
+                title
+                    .as_ref()
+                    .map_or(std::ptr::null(), |title| title.as_ptr()),
+                description
+                    .as_ref()
+                    .map_or(std::ptr::null(), |description| description.as_ptr()),
+                icon.as_ref().map_or(std::ptr::null(), |icon| icon.as_ptr()),
+                priority,
+                clip_priority.into(),
+            );
+        }
+    }
+
+    /// Ends an event started with
+    /// [`start_range_timeline_event`](Self::start_range_timeline_event).
+    ///
+    /// `end_offset_seconds` is the offset from now at which the event ends.
+    pub fn end_range_timeline_event(&self, event: TimelineEventHandle, end_offset_seconds: f32) {
+        if self.disabled {
+            return;
+        }
+
+        unsafe {
+            sys::SteamAPI_ISteamTimeline_EndRangeTimelineEvent(
+                self.timeline,
+                event.0,
+                end_offset_seconds,
+            )
         }
     }
 }
@@ -157,6 +157,7 @@ impl Into<sys::EUGCQuery> for UGCQueryType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FileType {
     Community,
     Microtransaction,
@@ -617,6 +618,7 @@ impl UGC {
         }
     }
 
+    /// Gets the current state of a workshop item on this client.
     pub fn item_state(&self, item: PublishedFileId) -> ItemState {
         unsafe {
             let state = sys::SteamAPI_ISteamUGC_GetItemState(self.ugc, item.0);
@@ -624,6 +626,10 @@ impl UGC {
         }
     }
 
+    /// Gets the progress of an item that is currently being downloaded.
+    ///
+    /// Returns the number of bytes downloaded so far and the total size of the
+    /// item, or `None` if the item isn't being downloaded.
     pub fn item_download_info(&self, item: PublishedFileId) -> Option<(u64, u64)> {
         unsafe {
             let mut current = 0u64;
@@ -641,6 +647,11 @@ impl UGC {
         }
     }
 
+    /// Gets info about an installed workshop item.
+    ///
+    /// Returns `None` if the item isn't installed, e.g. because it is still
+    /// being downloaded. Check [`ItemState::INSTALLED`] via
+    /// [`item_state()`](#method.item_state) before calling this.
     pub fn item_install_info(&self, item: PublishedFileId) -> Option<InstallInfo> {
         unsafe {
             let mut size_on_disk = 0u64;
@@ -667,6 +678,14 @@ impl UGC {
         }
     }
 
+    /// Downloads or updates a workshop item.
+    ///
+    /// If `high_priority` is `true` the item is placed at the front of the
+    /// download queue and other downloads are paused until it completes.
+    /// A [`DownloadItemResult`] callback is posted once the download finishes.
+    ///
+    /// Returns `false` if the request couldn't be started, e.g. because the
+    /// item id is invalid or the user is offline.
     pub fn download_item(&self, item: PublishedFileId, high_priority: bool) -> bool {
         unsafe { sys::SteamAPI_ISteamUGC_DownloadItem(self.ugc, item.0, high_priority) }
     }
@@ -866,6 +885,23 @@ impl UpdateHandle {
         self
     }
 
+    /// Sets the language of the title and description set with this update
+    ///
+    /// Uses the API language code, e.g. `"english"`. If this isn't set the
+    /// title and description are assumed to be in english.
+    #[must_use]
+    pub fn language(self, language: &str) -> Self {
+        unsafe {
+            let language = CString::new(language).unwrap();
+            assert!(sys::SteamAPI_ISteamUGC_SetItemUpdateLanguage(
+                self.ugc,
+                self.handle,
+                language.as_ptr()
+            ));
+        }
+        self
+    }
+
     #[must_use]
     pub fn preview_path(self, path: &Path) -> Self {
         unsafe {
@@ -989,6 +1025,11 @@ impl UpdateHandle {
         self
     }
 
+    /// Uploads the changes made to the item to the workshop
+    ///
+    /// The returned handle can be used to track the progress of the upload.
+    /// The callback receives the id of the item and whether the user needs
+    /// to accept the workshop legal agreement before the item becomes visible.
     pub fn submit<F>(self, change_note: Option<&str>, cb: F) -> UpdateWatchHandle
     where
         F: FnOnce(Result<(PublishedFileId, bool), SteamError>) + 'static + Send,
@@ -1314,6 +1355,11 @@ impl QueryHandle {
     }
 
     /// Sets whether to only return the total number of items.
+    ///
+    /// This is much faster when only the number of matching items is needed.
+    /// When enabled no item details are returned, so the results only contain
+    /// zeroed details and [`QueryResults::total_results`] is the only meaningful
+    /// value. See also [`fetch_total`](Self::fetch_total).
     pub fn set_return_total_only(self, return_total_only: bool) -> Self {
         let ok = unsafe {
             sys::SteamAPI_ISteamUGC_SetReturnTotalOnly(
@@ -1391,6 +1437,40 @@ impl QueryHandle {
         self
     }
 
+    /// Restricts the results to items created between `start` and `end`.
+    ///
+    /// Both times are unix timestamps in seconds. Only applies to
+    /// [`UGCQueryType::RankedByTextSearch`] queries.
+    pub fn set_time_created_date_range(self, start: u32, end: u32) -> Self {
+        let ok = unsafe {
+            sys::SteamAPI_ISteamUGC_SetTimeCreatedDateRange(
+                self.ugc,
+                self.handle.unwrap(),
+                start,
+                end,
+            )
+        };
+        debug_assert!(ok);
+        self
+    }
+
+    /// Restricts the results to items last updated between `start` and `end`.
+    ///
+    /// Both times are unix timestamps in seconds. Only applies to
+    /// [`UGCQueryType::RankedByTextSearch`] queries.
+    pub fn set_time_updated_date_range(self, start: u32, end: u32) -> Self {
+        let ok = unsafe {
+            sys::SteamAPI_ISteamUGC_SetTimeUpdatedDateRange(
+                self.ugc,
+                self.handle.unwrap(),
+                start,
+                end,
+            )
+        };
+        debug_assert!(ok);
+        self
+    }
+
     /// Adds a required key-value tag that must be present on all returned items.
     pub fn add_required_key_value_tag(self, key: &str, value: &str) -> Self {
         let key_cstr = CString::new(key).unwrap();
@@ -1745,10 +1825,20 @@ impl<'a> QueryResults<'a> {
             None
         }
     }
+
+    /// Gets the developer-set metadata associated with the item at the specified index as a string.
+    ///
+    /// You must call `set_return_metadata(true)` before fetching the query for this to work.
+    /// Invalid UTF-8 is replaced, use [`get_metadata`](Self::get_metadata) to get the raw bytes.
+    pub fn get_metadata_string(&self, index: u32) -> Option<String> {
+        self.get_metadata(index)
+            .map(|metadata| String::from_utf8_lossy(&metadata).into_owned())
+    }
 }
 
 /// Query result
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct QueryResult {
     pub published_file_id: PublishedFileId,
     pub creator_app_id: Option<AppId>,
@@ -6,7 +6,7 @@ use serial_test::serial;
 /// Access to the steam user interface
 pub struct User {
     pub(crate) user: *mut sys::ISteamUser,
-    pub(crate) _inner: Arc<Inner>,
+    pub(crate) inner: Arc<Inner>,
 }
 
 impl User {
@@ -149,6 +149,154 @@ impl User {
         }
     }
 
+    /// Requests an application ticket encrypted with the secret key of the
+    /// current app.
+    ///
+    /// `data` is included in the ticket and can be at most 128 bytes long.
+    /// Once the callback reports success the ticket can be retrieved with
+    /// [`encrypted_app_ticket()`](#method.encrypted_app_ticket).
+    ///
+    /// Only one request can be in flight at a time, further requests fail with
+    /// `SteamError::LimitExceeded` until the pending one completes.
+    pub fn request_encrypted_app_ticket<F>(&self, data: &[u8], cb: F)
+    where
+        F: FnOnce(SResult<()>) + 'static + Send,
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamUser_RequestEncryptedAppTicket(
+                self.user,
+                data.as_ptr() as *mut _,
+                data.len() as _,
+            );
+            register_call_result::<sys::EncryptedAppTicketResponse_t, _>(
+                &self.inner,
+                api_call,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else {
+                        crate::to_steam_result(v.m_eResult)
+                    })
+                },
+            );
+        }
+    }
+
+    /// Retrieves the encrypted app ticket requested with
+    /// [`request_encrypted_app_ticket()`](#method.request_encrypted_app_ticket).
+    ///
+    /// The ticket can only be decrypted with the encrypted app ticket key of
+    /// the app, which has to be obtained from the Steamworks partner site.
+    /// Decryption is usually done on a backend server using the
+    /// `sdkencryptedappticket` library shipped with the Steamworks SDK.
+    ///
+    /// Returns `None` if no ticket is available.
+    pub fn encrypted_app_ticket(&self) -> Option<Vec<u8>> {
+        unsafe {
+            let mut ticket = vec![0u8; 1024];
+            let mut ticket_len = 0;
+            if sys::SteamAPI_ISteamUser_GetEncryptedAppTicket(
+                self.user,
+                ticket.as_mut_ptr().cast(),
+                ticket.len() as _,
+                &mut ticket_len,
+            ) {
+                ticket.truncate(ticket_len as usize);
+                Some(ticket)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Starts voice recording.
+    ///
+    /// Once started, use [`get_voice()`](#method.get_voice) to get the recorded
+    /// data. This should be called when the user presses the push-to-talk key.
+    pub fn start_voice_recording(&self) {
+        unsafe { sys::SteamAPI_ISteamUser_StartVoiceRecording(self.user) }
+    }
+
+    /// Stops voice recording.
+    ///
+    /// Recording continues for a short while after this is called, so keep
+    /// calling [`get_voice()`](#method.get_voice) until it returns
+    /// `VoiceResult::NotRecording`.
+    pub fn stop_voice_recording(&self) {
+        unsafe { sys::SteamAPI_ISteamUser_StopVoiceRecording(self.user) }
+    }
+
+    /// Returns the number of bytes of compressed voice data that is ready to
+    /// be read with [`get_voice()`](#method.get_voice).
+    pub fn get_available_voice(&self) -> Result<u32, VoiceResult> {
+        unsafe {
+            let mut compressed = 0;
+            let res = sys::SteamAPI_ISteamUser_GetAvailableVoice(
+                self.user,
+                &mut compressed,
+                std::ptr::null_mut(),
+                0,
+            );
+            voice_result(res).map(|_| compressed)
+        }
+    }
+
+    /// Reads the recorded compressed voice data into `buf`.
+    ///
+    /// Returns the number of bytes written. The data can be sent to other
+    /// users and played back after passing it through
+    /// [`decompress_voice()`](#method.decompress_voice).
+    pub fn get_voice(&self, buf: &mut [u8]) -> Result<usize, VoiceResult> {
+        unsafe {
+            let mut written = 0;
+            let res = sys::SteamAPI_ISteamUser_GetVoice(
+                self.user,
+                true,
+                buf.as_mut_ptr().cast(),
+                buf.len() as _,
+                &mut written,
+                false,
+                std::ptr::null_mut(),
+                0,
+                std::ptr::null_mut(),
+                0,
+            );
+            voice_result(res).map(|_| written as usize)
+        }
+    }
+
+    /// Decompresses voice data from [`get_voice()`](#method.get_voice) into
+    /// 16-bit signed mono PCM samples at `desired_sample_rate`.
+    ///
+    /// Returns the number of samples written to `out`. Use
+    /// [`voice_optimal_sample_rate()`](#method.voice_optimal_sample_rate) for
+    /// the best quality.
+    pub fn decompress_voice(
+        &self,
+        compressed: &[u8],
+        out: &mut [i16],
+        desired_sample_rate: u32,
+    ) -> Result<usize, VoiceResult> {
+        unsafe {
+            let mut written = 0;
+            let res = sys::SteamAPI_ISteamUser_DecompressVoice(
+                self.user,
+                compressed.as_ptr().cast(),
+                compressed.len() as _,
+                out.as_mut_ptr().cast(),
+                std::mem::size_of_val(out) as _,
+                &mut written,
+                desired_sample_rate,
+            );
+            voice_result(res).map(|_| written as usize / std::mem::size_of::<i16>())
+        }
+    }
+
+    /// Returns the native sample rate of the Steam voice decoder.
+    pub fn voice_optimal_sample_rate(&self) -> u32 {
+        unsafe { sys::SteamAPI_ISteamUser_GetVoiceOptimalSampleRate(self.user) }
+    }
+
     /// Checks if the user owns a piece of DLC specified by app id.
     ///
     /// This can only be called after authenticating
@@ -194,6 +342,54 @@ pub enum AuthSessionError {
     ExpiredTicket,
 }
 
+/// Errors from the voice recording functions of [`User`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum VoiceResult {
+    /// The voice chat system isn't initialized
+    #[error("voice chat not initialized")]
+    NotInitialized,
+    /// Voice recording is not active
+    #[error("not recording")]
+    NotRecording,
+    /// No voice data is available
+    #[error("no voice data available")]
+    NoData,
+    /// The passed buffer is too small to hold the data
+    #[error("buffer too small")]
+    BufferTooSmall,
+    /// The compressed voice data is corrupted
+    #[error("voice data corrupted")]
+    DataCorrupted,
+    /// The user is not allowed to use voice chat
+    #[error("voice chat restricted")]
+    Restricted,
+    /// The voice data was compressed with an unsupported codec
+    #[error("unsupported codec")]
+    UnsupportedCodec,
+    /// The receiver is running an outdated client
+    #[error("receiver out of date")]
+    ReceiverOutOfDate,
+    /// The receiver didn't answer
+    #[error("receiver did not answer")]
+    ReceiverDidNotAnswer,
+}
+
+fn voice_result(res: sys::EVoiceResult) -> Result<(), VoiceResult> {
+    Err(match res {
+        sys::EVoiceResult::k_EVoiceResultOK => return Ok(()),
+        sys::EVoiceResult::k_EVoiceResultNotInitialized => VoiceResult::NotInitialized,
+        sys::EVoiceResult::k_EVoiceResultNotRecording => VoiceResult::NotRecording,
+        sys::EVoiceResult::k_EVoiceResultNoData => VoiceResult::NoData,
+        sys::EVoiceResult::k_EVoiceResultBufferTooSmall => VoiceResult::BufferTooSmall,
+        sys::EVoiceResult::k_EVoiceResultDataCorrupted => VoiceResult::DataCorrupted,
+        sys::EVoiceResult::k_EVoiceResultRestricted => VoiceResult::Restricted,
+        sys::EVoiceResult::k_EVoiceResultUnsupportedCodec => VoiceResult::UnsupportedCodec,
+        sys::EVoiceResult::k_EVoiceResultReceiverOutOfDate => VoiceResult::ReceiverOutOfDate,
+        sys::EVoiceResult::k_EVoiceResultReceiverDidNotAnswer => VoiceResult::ReceiverDidNotAnswer,
+        _ => unreachable!(),
+    })
+}
+
 #[test]
 #[serial]
 fn test_auth_dll() {
@@ -239,12 +435,14 @@ fn test_auth_dll() {
 /// A handle for an authentication ticket that can be used to cancel
 /// it.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AuthTicket(pub(crate) sys::HAuthTicket);
 
 /// Called when generating a authentication session ticket.
 ///
 /// This can be used to verify the ticket was created successfully.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AuthSessionTicketResponse {
     /// The ticket in question
     pub ticket: AuthTicket,
@@ -285,6 +483,7 @@ fn test_auth_webapi() {
 ///
 /// This can be used to verify the ticket was created successfully.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TicketForWebApiResponse {
     pub ticket_handle: AuthTicket,
     pub result: SResult<()>,
@@ -304,6 +503,7 @@ impl_callback!(cb: GetTicketForWebApiResponse_t => TicketForWebApiResponse {
 /// Called when an authentication ticket has been
 /// validated.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ValidateAuthTicketResponse {
     /// The steam id of the entity that provided the ticket
     pub steam_id: SteamId,
@@ -347,6 +547,9 @@ impl_callback!(cb: ValidateAuthTicketResponse_t => ValidateAuthTicketResponse {
             sys::EAuthSessionResponse::k_EAuthSessionResponsePublisherIssuedBan => {
                 Err(AuthSessionValidateError::PublisherIssuedBan)
             }
+            sys::EAuthSessionResponse::k_EAuthSessionResponseAuthTicketNetworkIdentityFailure => {
+                Err(AuthSessionValidateError::AuthTicketNetworkIdentityFailure)
+            }
             _ => unreachable!(),
         },
     }
@@ -411,6 +614,7 @@ impl_callback!(cb: SteamServerConnectFailure_t => SteamServerConnectFailure {
 
 /// Errors from `ValidateAuthTicketResponse`
 #[derive(Clone, Debug, Error)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AuthSessionValidateError {
     /// The user in question is not connected to steam
     #[error("user not connected to steam")]
@@ -442,6 +646,10 @@ pub enum AuthSessionValidateError {
     /// The user is banned from the game (not VAC)
     #[error("the user is banned")]
     PublisherIssuedBan,
+    /// The network identity in the ticket doesn't match the one of
+    /// the entity validating it
+    #[error("the authentication ticket was issued for a different identity")]
+    AuthTicketNetworkIdentityFailure,
 }
 
 /// Results from [`User::user_has_license_for_app`]
@@ -13,6 +13,11 @@ pub struct UserStats {
 }
 
 impl UserStats {
+    /// Looks up the leaderboard with the given name.
+    ///
+    /// The callback receives `None` if no leaderboard with that name exists. Leaderboards
+    /// can be created on the Steamworks website or via
+    /// [`find_or_create_leaderboard()`](#method.find_or_create_leaderboard).
     pub fn find_leaderboard<F>(&self, name: &str, cb: F)
     where
         F: FnOnce(Result<Option<Leaderboard>, SteamError>) + 'static + Send,
@@ -39,6 +44,10 @@ impl UserStats {
         }
     }
 
+    /// Looks up the leaderboard with the given name, creating it if it doesn't exist yet.
+    ///
+    /// `sort_method` and `display_type` are only used when the leaderboard has to be
+    /// created, an existing leaderboard keeps the settings it was created with.
     pub fn find_or_create_leaderboard<F>(
         &self,
         name: &str,
@@ -96,6 +105,12 @@ impl UserStats {
         }
     }
 
+    /// Uploads a score to the given leaderboard.
+    ///
+    /// `details` can hold up to 64 extra values that are stored alongside the score, they
+    /// are returned as part of a [`LeaderboardEntry`] when downloading entries.
+    ///
+    /// The callback receives `None` if the upload failed.
     pub fn upload_leaderboard_score<F>(
         &self,
         leaderboard: &Leaderboard,
@@ -146,6 +161,13 @@ impl UserStats {
         }
     }
 
+    /// Downloads a range of entries from the given leaderboard.
+    ///
+    /// For [`LeaderboardDataRequest::Global`] `start` and `end` are absolute ranks, for
+    /// [`LeaderboardDataRequest::GlobalAroundUser`] they are offsets relative to the
+    /// current user's rank. Both are ignored for [`LeaderboardDataRequest::Friends`].
+    ///
+    /// At most `max_details_len` detail values are read for each entry.
     pub fn download_leaderboard_entries<F>(
         &self,
         leaderboard: &Leaderboard,
@@ -427,6 +449,88 @@ impl UserStats {
         }
     }
 
+    /// Gets the value of a given stat for the specified user
+    ///
+    /// The specified stat must exist and match the type set on the Steamworks App Admin website.
+    ///
+    /// Requires [`request_user_stats()`](#method.request_user_stats) to have been called
+    /// and a successful [`UserStatsReceived`](./struct.UserStatsReceived.html) callback processed
+    /// for the user.
+    pub fn get_user_stat_i32(&self, user: SteamId, name: &str) -> Result<i32, ()> {
+        let name = CString::new(name).unwrap();
+
+        let mut value: i32 = 0;
+        let success = unsafe {
+            sys::SteamAPI_ISteamUserStats_GetUserStatInt32(
+                self.user_stats,
+                user.0,
+                name.as_ptr(),
+                &mut value,
+            )
+        };
+        if success {
+            Ok(value)
+        } else {
+            Err(())
+        }
+    }
+
+    /// Gets the value of a given stat for the specified user
+    ///
+    /// The specified stat must exist and match the type set on the Steamworks App Admin website.
+    ///
+    /// Requires [`request_user_stats()`](#method.request_user_stats) to have been called
+    /// and a successful [`UserStatsReceived`](./struct.UserStatsReceived.html) callback processed
+    /// for the user.
+    pub fn get_user_stat_f32(&self, user: SteamId, name: &str) -> Result<f32, ()> {
+        let name = CString::new(name).unwrap();
+
+        let mut value: f32 = 0.0;
+        let success = unsafe {
+            sys::SteamAPI_ISteamUserStats_GetUserStatFloat(
+                self.user_stats,
+                user.0,
+                name.as_ptr(),
+                &mut value,
+            )
+        };
+        if success {
+            Ok(value)
+        } else {
+            Err(())
+        }
+    }
+
+    /// Gets the unlock status of an achievement for the specified user
+    ///
+    /// Returns whether the achievement is unlocked together with the time it was
+    /// unlocked at, in seconds since the unix epoch. The time is 0 if the achievement
+    /// is locked.
+    ///
+    /// Requires [`request_user_stats()`](#method.request_user_stats) to have been called
+    /// and a successful [`UserStatsReceived`](./struct.UserStatsReceived.html) callback processed
+    /// for the user.
+    pub fn get_user_achievement(&self, user: SteamId, name: &str) -> Result<(bool, u32), ()> {
+        let name = CString::new(name).unwrap();
+
+        let mut achieved = false;
+        let mut unlock_time = 0;
+        let success = unsafe {
+            sys::SteamAPI_ISteamUserStats_GetUserAchievementAndUnlockTime(
+                self.user_stats,
+                user.0,
+                name.as_ptr(),
+                &mut achieved,
+                &mut unlock_time,
+            )
+        };
+        if success {
+            Ok((achieved, unlock_time))
+        } else {
+            Err(())
+        }
+    }
+
     /// Access achievement API for a given achievement 'API Name'.
     ///
     /// Requires [`request_current_stats()`](#method.request_current_stats) to have been called
@@ -469,15 +573,99 @@ impl UserStats {
         let mut names = Vec::new();
 
         for i in 0..num {
-            unsafe {
-                let name = sys::SteamAPI_ISteamUserStats_GetAchievementName(self.user_stats, i);
+            names.push(self.get_achievement_name(i));
+        }
+        Some(names)
+    }
 
-                let c_str = CStr::from_ptr(name).to_string_lossy().into_owned();
+    /// Gets the 'API name' for an achievement index between 0 and
+    /// [`get_num_achievements()`](#method.get_num_achievements).
+    ///
+    /// The returned name can be passed to [`achievement()`](#method.achievement) to query
+    /// the achievement's display attributes and icon.
+    ///
+    /// Returns an empty string if `index` is not a valid index.
+    pub fn get_achievement_name(&self, index: u32) -> String {
+        unsafe {
+            let name = sys::SteamAPI_ISteamUserStats_GetAchievementName(self.user_stats, index);
+            CStr::from_ptr(name).to_string_lossy().into_owned()
+        }
+    }
+
+    /// Iterates over the achievements of the current app, ordered from the most
+    /// to the least achieved by players.
+    ///
+    /// Requires [`request_global_achievement_percentages()`](#method.request_global_achievement_percentages)
+    /// to have completed successfully, otherwise the iterator is empty.
+    pub fn get_most_achieved_stat_info_iter(&self) -> AchievementInfoIter<'_> {
+        AchievementInfoIter {
+            user_stats: self,
+            previous: None,
+        }
+    }
+}
 
-                names.push(c_str);
+/// The global completion rate of an achievement, as returned by
+/// [`UserStats::get_most_achieved_stat_info_iter`]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AchievementInfo {
+    /// The 'API name' of the achievement
+    pub name: String,
+    /// The percentage of players that have unlocked the achievement
+    pub achieved_percent: f32,
+    /// Whether the current user has unlocked the achievement
+    pub achieved: bool,
+}
+
+/// Iterator over achievement completion rates, see
+/// [`UserStats::get_most_achieved_stat_info_iter`]
+pub struct AchievementInfoIter<'a> {
+    user_stats: &'a UserStats,
+    previous: Option<std::os::raw::c_int>,
+}
+
+impl Iterator for AchievementInfoIter<'_> {
+    type Item = AchievementInfo;
+
+    fn next(&mut self) -> Option<AchievementInfo> {
+        if self.previous == Some(-1) {
+            return None;
+        }
+        let mut name = [0 as c_char; 256];
+        let mut achieved_percent = 0.0;
+        let mut achieved = false;
+        let index = unsafe {
+            match self.previous {
+                None => sys::SteamAPI_ISteamUserStats_GetMostAchievedAchievementInfo(
+                    self.user_stats.user_stats,
+                    name.as_mut_ptr(),
+                    name.len() as _,
+                    &mut achieved_percent,
+                    &mut achieved,
+                ),
+                Some(previous) => sys::SteamAPI_ISteamUserStats_GetNextMostAchievedAchievementInfo(
+                    self.user_stats.user_stats,
+                    previous,
+                    name.as_mut_ptr(),
+                    name.len() as _,
+                    &mut achieved_percent,
+                    &mut achieved,
+                ),
             }
+        };
+        self.previous = Some(index);
+        if index == -1 {
+            return None;
         }
-        Some(names)
+        let name = unsafe { CStr::from_ptr(name.as_ptr()) }
+            .to_string_lossy()
+            .into_owned();
+        Some(AchievementInfo {
+            name,
+            achieved_percent,
+            achieved,
+        })
     }
 }
 
@@ -490,6 +678,8 @@ pub struct LeaderboardEntry {
     pub details: Vec<i32>,
 }
 
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum LeaderboardDataRequest {
     Global,
     GlobalAroundUser,
@@ -87,6 +87,35 @@ impl AchievementHelper<'_> {
         }
     }
 
+    /// Shows the user a pop-up notification with the current progress of an achievement.
+    ///
+    /// Calling this function will NOT set the progress or unlock the achievement, the game must
+    /// do that manually by calling [`set_stat_i32()`](../struct.UserStats.html#method.set_stat_i32)
+    /// or [`set()`](#method.set).
+    ///
+    /// Triggers a [`UserAchievementStored`](../struct.UserAchievementStored.html) callback.
+    ///
+    /// The notification is shown through the Steam overlay, so this has no effect in offline mode.
+    ///
+    /// Fails if this achievement's 'API Name' is unknown, the achievement is already unlocked,
+    /// `current_progress` is not less than `max_progress`, or unsuccessful
+    /// [`UserStatsReceived`](../struct.UserStatsReceived.html).
+    pub fn indicate_progress(&self, current_progress: u32, max_progress: u32) -> Result<(), ()> {
+        let success = unsafe {
+            sys::SteamAPI_ISteamUserStats_IndicateAchievementProgress(
+                self.parent.user_stats,
+                self.name.as_ptr(),
+                current_progress,
+                max_progress,
+            )
+        };
+        if success {
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
     /// Returns the percentage of users who have unlocked the specified achievement.
     ///
     /// You must have called `request_global_achievement_percentages()` and it needs to return
@@ -12,18 +12,43 @@ pub struct Utils {
     pub(crate) _inner: Arc<Inner>,
 }
 
+/// Called when the Big Picture gamepad text input has been closed.
+///
+/// The entered text is read as soon as the callback arrives, so it doesn't
+/// have to be retrieved separately.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GamepadTextInputDismissed {
-    pub submitted_text_len: Option<u32>,
+    /// Whether the user submitted the text, `false` if the input was cancelled
+    pub submitted: bool,
+    /// The entered text, empty if the input was cancelled
+    pub submitted_text: String,
 }
 
 impl_callback!(cb: GamepadTextInputDismissed_t => GamepadTextInputDismissed {
+    let mut submitted_text = String::new();
+    if cb.m_bSubmitted {
+        let utils = sys::SteamAPI_SteamUtils_v010();
+        // Leave room for the nul terminator, the length doesn't always include it
+        let mut buf = vec![0u8; cb.m_unSubmittedText as usize + 1];
+        if sys::SteamAPI_ISteamUtils_GetEnteredGamepadTextInput(
+            utils,
+            buf.as_mut_ptr().cast(),
+            buf.len() as u32,
+        ) {
+            let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+            buf.truncate(len);
+            submitted_text = String::from_utf8_lossy(&buf).into_owned();
+        }
+    }
     Self {
-        submitted_text_len: cb.m_bSubmitted.then_some(cb.m_unSubmittedText),
+        submitted: cb.m_bSubmitted,
+        submitted_text,
     }
 });
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FloatingGamepadTextInputDismissed;
 
 impl_callback!(_cb: FloatingGamepadTextInputDismissed_t => FloatingGamepadTextInputDismissed {
@@ -37,8 +62,10 @@ pub enum NotificationPosition {
     BottomRight,
 }
 
+/// The kind of text the gamepad text input accepts
 pub enum GamepadTextInputMode {
     Normal,
+    /// The entered text is hidden
     Password,
 }
 
@@ -55,6 +82,7 @@ impl From<GamepadTextInputMode> for sys::EGamepadTextInputMode {
     }
 }
 
+/// Whether the gamepad text input accepts line breaks
 pub enum GamepadTextInputLineMode {
     SingleLine,
     MultipleLines,
@@ -159,6 +187,19 @@ impl Utils {
         }
     }
 
+    /// Returns the number of seconds since the application was last active.
+    ///
+    /// This is mostly used for idle detection.
+    pub fn get_seconds_since_app_active(&self) -> u32 {
+        unsafe { sys::SteamAPI_ISteamUtils_GetSecondsSinceAppActive(self.utils) }
+    }
+
+    /// Returns the number of seconds since the user last moved the mouse or
+    /// otherwise interacted with the computer.
+    pub fn get_seconds_since_computer_active(&self) -> u32 {
+        unsafe { sys::SteamAPI_ISteamUtils_GetSecondsSinceComputerActive(self.utils) }
+    }
+
     /// Returns the current real time on the Steam servers
     /// in Unix epoch format (seconds since 1970/1/1 UTC).
     pub fn get_server_real_time(&self) -> u32 {
@@ -204,23 +245,16 @@ impl Utils {
 
     /// Gets the gamepad text input from the Big Picture overlay.
     ///
-    /// This must be called within the `show_gamepad_text_input` callback. Returns `Some(String)` if user submitted the
-    /// text, and `None` otherwise
+    /// Returns `Some(String)` if user submitted the text, and `None` otherwise.
+    /// The text is already read when the callback is created, so this is
+    /// equivalent to checking [`GamepadTextInputDismissed::submitted`].
     pub fn get_entered_gamepad_text_input(
         &self,
         dismissed_data: &GamepadTextInputDismissed,
     ) -> Option<String> {
-        unsafe {
-            let len = dismissed_data.submitted_text_len?;
-            let mut buf = vec![0u8; len as usize];
-
-            sys::SteamAPI_ISteamUtils_GetEnteredGamepadTextInput(
-                self.utils,
-                buf.as_mut_ptr().cast(),
-                len,
-            )
-            .then(|| String::from_utf8(buf).expect("Steamworks returned invalid UTF-8 string"))
-        }
+        dismissed_data
+            .submitted
+            .then(|| dismissed_data.submitted_text.clone())
     }
 
     /// Checks if Steam & the Steam Overlay are running in Big Picture mode.
@@ -231,12 +265,40 @@ impl Utils {
         unsafe { sys::SteamAPI_ISteamUtils_IsSteamInBigPictureMode(self.utils) }
     }
 
+    /// Returns the current battery power in percent, between 0 and 100.
+    ///
+    /// Returns 255 if the computer isn't running on battery power, e.g. a
+    /// desktop or a laptop that is plugged in.
+    pub fn get_current_battery_power(&self) -> u8 {
+        unsafe { sys::SteamAPI_ISteamUtils_GetCurrentBatteryPower(self.utils) }
+    }
+
+    /// Returns whether the HMD content will be streamed via Steam Remote Play.
+    pub fn is_vr_headset_streaming_enabled(&self) -> bool {
+        unsafe { sys::SteamAPI_ISteamUtils_IsVRHeadsetStreamingEnabled(self.utils) }
+    }
+
+    /// Sets whether the HMD content will be streamed via Steam Remote Play.
+    ///
+    /// If this is disabled the content of the game window is streamed instead
+    /// of the VR headset view. This is enabled by default.
+    pub fn set_vr_headset_streaming_enabled(&self, enabled: bool) {
+        unsafe { sys::SteamAPI_ISteamUtils_SetVRHeadsetStreamingEnabled(self.utils, enabled) }
+    }
+
     /// Checks if Steam is running on a Steam Deck device.
     pub fn is_steam_running_on_steam_deck(&self) -> bool {
         unsafe { sys::SteamAPI_ISteamUtils_IsSteamRunningOnSteamDeck(self.utils) }
     }
 
     /// Activates the Big Picture text input dialog which only supports gamepad input.
+    ///
+    /// `description` is shown to the user above the input field and
+    /// `existing_text` pre-fills it. Once the user closes the dialog the
+    /// callback receives a [`GamepadTextInputDismissed`] containing the
+    /// entered text.
+    ///
+    /// Returns `false` if the Big Picture overlay isn't available.
     pub fn show_gamepad_text_input<F>(
         &self,
         input_mode: GamepadTextInputMode,